@@ -0,0 +1,86 @@
+//! Expansion of abbreviations, such as "Dr." or "e.g.".
+
+use super::constants::{
+    ABBREVIATIONS, CONTEXT_ABBREVIATIONS, NUMBER_ABBREVIATIONS, SENTENCE_FINAL_ABBREVIATIONS,
+};
+
+/// Convert an abbreviation to the form that is used for lookups, i.e. lowercase and without the
+/// trailing dot, e.g. "E.g." becomes "e.g".
+pub fn lookup_key(abbreviation: &str) -> String {
+    abbreviation
+        .strip_suffix('.')
+        .unwrap_or(abbreviation)
+        .to_lowercase()
+}
+
+/// Check if the word is a built-in abbreviation. The word has to be in the form returned by
+/// [`lookup_key`]. Some abbreviations are only abbreviations in front of numbers, which is
+/// specified by `followed_by_number`.
+pub fn is_abbreviation(key: &str, followed_by_number: bool) -> bool {
+    ABBREVIATIONS.contains_key(key)
+        || CONTEXT_ABBREVIATIONS.contains_key(key)
+        || (followed_by_number && NUMBER_ABBREVIATIONS.contains_key(key))
+}
+
+/// Check if the abbreviation can end a sentence, e.g. "etc." or "Inc.", but not "Dr.".
+pub fn can_end_sentence(abbreviation: &str) -> bool {
+    SENTENCE_FINAL_ABBREVIATIONS.contains(lookup_key(abbreviation).as_str())
+}
+
+/// Expand a built-in abbreviation as matched by `Token::AbbreviatedWord`, e.g. "Mr." becomes "Mister".
+/// Some abbreviations depend on the next word, e.g. "St." is "Saint" in "St. Louis", but "Street"
+/// in "Main St.".
+pub fn expand(abbreviation: &str, next_word: Option<&str>) -> Option<&'static str> {
+    let key = lookup_key(abbreviation);
+
+    if let Some((capitalized, other)) = CONTEXT_ABBREVIATIONS.get(key.as_str()) {
+        let followed_by_name = next_word
+            .and_then(|word| word.chars().next())
+            .is_some_and(|c| c.is_uppercase());
+        return Some(if followed_by_name { capitalized } else { other });
+    }
+
+    ABBREVIATIONS
+        .get(key.as_str())
+        .or_else(|| NUMBER_ABBREVIATIONS.get(key.as_str()))
+        .copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expand_test() {
+        assert_eq!(expand("Mrs.", None), Some("Missus"));
+        assert_eq!(expand("e.g.", None), Some("for example"));
+        assert_eq!(expand("No.", Some("5")), Some("number"));
+        assert_eq!(expand("Jan.", Some("2024")), Some("January"));
+        assert_eq!(expand("word.", None), None);
+    }
+
+    #[test]
+    fn context_test() {
+        assert_eq!(expand("St.", Some("Louis")), Some("Saint"));
+        assert_eq!(expand("St.", Some("and")), Some("Street"));
+        assert_eq!(expand("St.", None), Some("Street"));
+        assert_eq!(expand("Dr.", Some("Smith")), Some("Doctor"));
+    }
+
+    #[test]
+    fn number_abbreviation_test() {
+        assert!(is_abbreviation("no", true));
+        assert!(!is_abbreviation("no", false));
+        assert!(is_abbreviation("jan", true));
+        assert!(!is_abbreviation("jan", false));
+    }
+
+    #[test]
+    fn can_end_sentence_test() {
+        assert!(can_end_sentence("etc."));
+        assert!(can_end_sentence("Inc."));
+        assert!(!can_end_sentence("Dr."));
+        assert!(!can_end_sentence("U.S."));
+        assert!(!can_end_sentence("Jr."));
+    }
+}
@@ -0,0 +1,111 @@
+//! Decomposition of words with clitics, such as "Smith's" or "Gemini'll", which are often missing from
+//! the dicts although the word without the clitic is found.
+
+/// Clitics with their phonemes after a vowel and after a consonant. "'s" is handled separately, since it has
+/// three forms. The plural possessive "s'" is not included, since it does not change the pronunciation.
+const CLITICS: [(&str, &str, &str); 5] = [
+    ("n't", "nt", "ənt"),
+    ("'ll", "l", "əl"),
+    ("'ve", "v", "əv"),
+    ("'re", "ɹ", "əɹ"),
+    ("'d", "d", "əd"),
+];
+
+/// Vowels, including the diphthongs written as single uppercase letters (e.g. "A" for "eɪ").
+const VOWELS: &str = "AIOWYaeiouæɑɐɒɔəɚɛɜɪʊʌᵻ";
+
+/// Consonants that are followed by "ɪz" instead of "s" or "z", e.g. "Bush's".
+const SIBILANTS: &str = "szʃʒʧʤ";
+
+/// Voiceless consonants that are followed by "s" instead of "z", e.g. "Kate's".
+const VOICELESS: &str = "ptkfθ";
+
+/// Split a known clitic from the end of the word, e.g. "Smith's" becomes ("Smith", "'s"). Case is ignored.
+pub fn split(word: &str) -> Option<(&str, &str)> {
+    let lowercase = word.to_lowercase();
+    // Lowercasing can change the length of non-ASCII words, in which case the byte offsets would not match
+    if lowercase.len() != word.len() {
+        return None;
+    }
+
+    CLITICS
+        .iter()
+        .map(|(clitic, _, _)| *clitic)
+        .chain(["'s"])
+        .find(|clitic| lowercase.ends_with(clitic))
+        .map(|clitic| word.split_at(word.len() - clitic.len()))
+        .filter(|(stem, _)| !stem.is_empty() && !stem.ends_with('\''))
+}
+
+/// Attach the phonemes of a clitic to the phonemes of its stem. The form of the clitic depends on the last
+/// phoneme of the stem, e.g. "'s" is "ɪz" after sibilants ("Bush's"), "s" after voiceless consonants
+/// ("Kate's") and "z" otherwise ("Gemini's").
+pub fn attach(stem_phonemes: &str, clitic: &str) -> String {
+    let after_vowel = last_phoneme(stem_phonemes).is_some_and(|c| VOWELS.contains(c));
+
+    let clitic = clitic.to_lowercase();
+    let clitic_phonemes = match clitic.as_str() {
+        "'s" => s_allomorph(stem_phonemes),
+        _ => CLITICS
+            .iter()
+            .find(|(c, _, _)| *c == clitic)
+            .map_or(
+                "",
+                |(_, vowel, consonant)| {
+                    if after_vowel {
+                        vowel
+                    } else {
+                        consonant
+                    }
+                },
+            ),
+    };
+
+    format!("{}{}", stem_phonemes, clitic_phonemes)
+}
+
+/// Get the form of "'s" or the plural "-s" after the stem: "ɪz" after sibilants, "s" after voiceless
+/// consonants and "z" otherwise.
+pub fn s_allomorph(stem_phonemes: &str) -> &'static str {
+    match last_phoneme(stem_phonemes) {
+        Some(c) if SIBILANTS.contains(c) => "ɪz",
+        Some(c) if VOICELESS.contains(c) => "s",
+        _ => "z",
+    }
+}
+
+/// Get the last phoneme of a transcription, ignoring stress and length marks.
+pub fn last_phoneme(phonemes: &str) -> Option<char> {
+    phonemes
+        .chars()
+        .rev()
+        .find(|c| !matches!(c, 'ˈ' | 'ˌ' | 'ː'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_test() {
+        assert_eq!(split("Smith's"), Some(("Smith", "'s")));
+        assert_eq!(split("Gemini'll"), Some(("Gemini", "'ll")));
+        assert_eq!(split("COULDN'T"), Some(("COULD", "N'T")));
+        assert_eq!(split("'s"), None);
+        assert_eq!(split("word"), None);
+    }
+
+    #[test]
+    fn possessive_test() {
+        assert_eq!(attach("bˈʊʃ", "'s"), "bˈʊʃɪz");
+        assert_eq!(attach("kˈAt", "'s"), "kˈAts");
+        assert_eq!(attach("ʤˈɛmənI", "'s"), "ʤˈɛmənIz");
+    }
+
+    #[test]
+    fn contraction_test() {
+        assert_eq!(attach("ʤˈɛmənI", "'ll"), "ʤˈɛmənIl");
+        assert_eq!(attach("kˈʊd", "n't"), "kˈʊdənt");
+        assert_eq!(attach("ðA", "'re"), "ðAɹ");
+    }
+}
@@ -0,0 +1,93 @@
+//! Splitting of unhyphenated compounds, such as "smartwatch" or "firetruckdriver", into known words.
+
+/// Parts shorter than this are not considered, since the dicts contain many short entries (e.g. single
+/// letters) that would lead to nonsensical splits.
+const MIN_PART_LENGTH: usize = 3;
+
+/// Longer words are not split, to limit the number of lookups.
+const MAX_COMPOUND_LENGTH: usize = 40;
+
+/// Split a word into two or more parts for which `is_word` returns true, e.g. "firetruckdriver" becomes
+/// ["fire", "truck", "driver"]. Splits with fewer parts are preferred, and among those the ones with longer
+/// parts. Returns `None` if there is no such split.
+pub fn split(word: &str, is_word: impl Fn(&str) -> bool) -> Option<Vec<&str>> {
+    // Byte offsets of all characters, plus the end of the word
+    let boundaries: Vec<usize> = word
+        .char_indices()
+        .map(|(i, _)| i)
+        .chain([word.len()])
+        .collect();
+    let length = boundaries.len() - 1;
+    if !(2 * MIN_PART_LENGTH..=MAX_COMPOUND_LENGTH).contains(&length) {
+        return None;
+    }
+
+    // best[end] is the best split of the first `end` characters, as (number of parts, sum of squared part
+    // lengths, start of the last part). Larger sums mean longer parts.
+    let mut best: Vec<Option<(usize, usize, usize)>> = vec![None; length + 1];
+    best[0] = Some((0, 0, 0));
+    for end in MIN_PART_LENGTH..=length {
+        for start in 0..=end - MIN_PART_LENGTH {
+            let Some((parts, squares, _)) = best[start] else {
+                continue;
+            };
+            if !is_word(&word[boundaries[start]..boundaries[end]]) {
+                continue;
+            }
+
+            let part_length = end - start;
+            let candidate = (parts + 1, squares + part_length * part_length, start);
+            let is_better = best[end].is_none_or(|(best_parts, best_squares, _)| {
+                (candidate.0, std::cmp::Reverse(candidate.1))
+                    < (best_parts, std::cmp::Reverse(best_squares))
+            });
+            if is_better {
+                best[end] = Some(candidate);
+            }
+        }
+    }
+
+    let (parts, _, _) = best[length]?;
+    if parts < 2 {
+        return None;
+    }
+
+    let mut split = Vec::with_capacity(parts);
+    let mut end = length;
+    while end > 0 {
+        let (_, _, start) = best[end]?;
+        split.push(&word[boundaries[start]..boundaries[end]]);
+        end = start;
+    }
+    split.reverse();
+
+    Some(split)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_word(word: &str) -> bool {
+        [
+            "fire", "truck", "driver", "fir", "word", "list", "smart", "watch", "art", "wat", "ch",
+        ]
+        .contains(&word)
+    }
+
+    #[test]
+    fn split_test() {
+        assert_eq!(
+            split("firetruckdriver", is_word),
+            Some(vec!["fire", "truck", "driver"])
+        );
+        assert_eq!(split("wordlist", is_word), Some(vec!["word", "list"]));
+        assert_eq!(split("smartwatch", is_word), Some(vec!["smart", "watch"]));
+    }
+
+    #[test]
+    fn no_split_test() {
+        assert_eq!(split("word", is_word), None);
+        assert_eq!(split("wordxyz", is_word), None);
+    }
+}
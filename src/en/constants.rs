@@ -1,4 +1,4 @@
-use phf::phf_map;
+use phf::{phf_map, phf_set};
 
 pub static DIGIT2WORD: phf::Map<char, &'static str> = phf_map! {
     '0' => "oh",
@@ -13,6 +13,384 @@ pub static DIGIT2WORD: phf::Map<char, &'static str> = phf_map! {
     '9' => "nine"
 };
 
+/// Words for the numbers 0 to 19, indexed by their value.
+pub static ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+/// Words for the multiples of ten, indexed by the tens digit. The first two entries are never used.
+pub static TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+/// Words for the powers of one thousand, indexed by the exponent.
+pub static SCALES: [&str; 5] = ["", "thousand", "million", "billion", "trillion"];
+
+/// Ordinal forms of number words that are not formed by simply appending "th".
+pub static IRREGULAR_ORDINALS: phf::Map<&'static str, &'static str> = phf_map! {
+    "one" => "first",
+    "two" => "second",
+    "three" => "third",
+    "five" => "fifth",
+    "eight" => "eighth",
+    "nine" => "ninth",
+    "twelve" => "twelfth",
+    "oh" => "zeroth",
+};
+
+/// Singular and plural names of a currency unit, e.g. ("dollar", "dollars").
+pub type CurrencyUnitNames = (&'static str, &'static str);
+
+/// Names of a currency and its fractional unit (1/100 of the main unit), if it is commonly used.
+pub struct CurrencyNames {
+    pub unit: CurrencyUnitNames,
+    pub subunit: Option<CurrencyUnitNames>,
+}
+
+/// Maps currency symbols to the names of the currency.
+pub static CURRENCIES: phf::Map<char, CurrencyNames> = phf_map! {
+    '$' => CurrencyNames { unit: ("dollar", "dollars"), subunit: Some(("cent", "cents")) },
+    '€' => CurrencyNames { unit: ("euro", "euros"), subunit: Some(("cent", "cents")) },
+    '£' => CurrencyNames { unit: ("pound", "pounds"), subunit: Some(("penny", "pence")) },
+    '¥' => CurrencyNames { unit: ("yen", "yen"), subunit: None },
+};
+
+/// Maps magnitude suffixes of amounts (as in "$5k" or "€1.2M") to their number words.
+pub static MAGNITUDE_SUFFIXES: phf::Map<&'static str, &'static str> = phf_map! {
+    "k" => "thousand",
+    "K" => "thousand",
+    "m" => "million",
+    "M" => "million",
+    "b" => "billion",
+    "B" => "billion",
+    "bn" => "billion",
+    "tn" => "trillion",
+};
+
+/// Maps abbreviations of units of measurement to their singular and plural names. Abbreviations are
+/// case sensitive. Some abbreviations are left out on purpose, because they are too ambiguous,
+/// e.g. "s" (as in "1990s"), "in" or "t".
+pub static UNITS: phf::Map<&'static str, (&'static str, &'static str)> = phf_map! {
+    // Length
+    "mm" => ("millimeter", "millimeters"),
+    "cm" => ("centimeter", "centimeters"),
+    "m" => ("meter", "meters"),
+    "km" => ("kilometer", "kilometers"),
+    "ft" => ("foot", "feet"),
+    "yd" => ("yard", "yards"),
+    "mi" => ("mile", "miles"),
+    // Weight
+    "mg" => ("milligram", "milligrams"),
+    "g" => ("gram", "grams"),
+    "kg" => ("kilogram", "kilograms"),
+    "oz" => ("ounce", "ounces"),
+    "lb" => ("pound", "pounds"),
+    "lbs" => ("pound", "pounds"),
+    // Volume
+    "ml" => ("milliliter", "milliliters"),
+    "mL" => ("milliliter", "milliliters"),
+    "l" => ("liter", "liters"),
+    "L" => ("liter", "liters"),
+    "gal" => ("gallon", "gallons"),
+    // Speed
+    "mph" => ("mile per hour", "miles per hour"),
+    "kph" => ("kilometer per hour", "kilometers per hour"),
+    "km/h" => ("kilometer per hour", "kilometers per hour"),
+    "m/s" => ("meter per second", "meters per second"),
+    // Time
+    "ms" => ("millisecond", "milliseconds"),
+    "sec" => ("second", "seconds"),
+    "min" => ("minute", "minutes"),
+    "h" => ("hour", "hours"),
+    "hr" => ("hour", "hours"),
+    "hrs" => ("hour", "hours"),
+    // Temperature
+    "°" => ("degree", "degrees"),
+    "°C" => ("degree Celsius", "degrees Celsius"),
+    "°F" => ("degree Fahrenheit", "degrees Fahrenheit"),
+    // Data
+    "KB" => ("kilobyte", "kilobytes"),
+    "kB" => ("kilobyte", "kilobytes"),
+    "MB" => ("megabyte", "megabytes"),
+    "GB" => ("gigabyte", "gigabytes"),
+    "TB" => ("terabyte", "terabytes"),
+    "Mbps" => ("megabit per second", "megabits per second"),
+    "Gbps" => ("gigabit per second", "gigabits per second"),
+    // Frequency
+    "Hz" => ("hertz", "hertz"),
+    "kHz" => ("kilohertz", "kilohertz"),
+    "MHz" => ("megahertz", "megahertz"),
+    "GHz" => ("gigahertz", "gigahertz"),
+    // Energy and power
+    "V" => ("volt", "volts"),
+    "W" => ("watt", "watts"),
+    "kW" => ("kilowatt", "kilowatts"),
+    "kWh" => ("kilowatt hour", "kilowatt hours"),
+    "mAh" => ("milliamp hour", "milliamp hours"),
+    "kcal" => ("kilocalorie", "kilocalories"),
+};
+
+/// Maps abbreviations (lowercase, without the trailing dot) to their expansions.
+pub static ABBREVIATIONS: phf::Map<&'static str, &'static str> = phf_map! {
+    // Titles
+    "mr" => "Mister",
+    "mrs" => "Missus",
+    "ms" => "Miz",
+    "prof" => "Professor",
+    "jr" => "Junior",
+    "sr" => "Senior",
+    "capt" => "Captain",
+    "lt" => "Lieutenant",
+    "sgt" => "Sergeant",
+    "rev" => "Reverend",
+    "hon" => "Honorable",
+    // Addresses
+    "ave" => "Avenue",
+    "blvd" => "Boulevard",
+    "rd" => "Road",
+    "ln" => "Lane",
+    "mt" => "Mount",
+    // Companies
+    "inc" => "Incorporated",
+    "ltd" => "Limited",
+    "co" => "Company",
+    "corp" => "Corporation",
+    "dept" => "department",
+    // Latin
+    "e.g" => "for example",
+    "i.e" => "that is",
+    "vs" => "versus",
+    "etc" => "et cetera",
+    "approx" => "approximately",
+    // Other
+    "u.s" => "U S",
+    "u.k" => "U K",
+    "a.m" => "A M",
+    "p.m" => "P M",
+};
+
+/// Abbreviations (lowercase, without the trailing dot) that often end a sentence, e.g. "etc." in "apples,
+/// pears, etc. The shop". Their dot also ends the sentence if they are followed by a capitalized word.
+/// Abbreviations that are often followed by a name, such as "U.S." in "U.S. Army" or "Jr." in "King Jr.
+/// Day", are not included.
+pub static SENTENCE_FINAL_ABBREVIATIONS: phf::Set<&'static str> = phf_set! {
+    "etc",
+    "inc",
+    "ltd",
+    "co",
+    "corp",
+};
+
+/// Abbreviations with two expansions. The first one is used if the abbreviation is followed by
+/// a capitalized word ("St. Louis", "Dr. Smith"), the second one otherwise ("Main St.", "Elm Dr.").
+pub static CONTEXT_ABBREVIATIONS: phf::Map<&'static str, (&'static str, &'static str)> = phf_map! {
+    "st" => ("Saint", "Street"),
+    "dr" => ("Doctor", "Drive"),
+};
+
+/// Abbreviations that are only considered abbreviations if they are followed by a number,
+/// e.g. "No. 5", since they are regular words otherwise.
+pub static NUMBER_ABBREVIATIONS: phf::Map<&'static str, &'static str> = phf_map! {
+    "no" => "number",
+    "nos" => "numbers",
+    "vol" => "volume",
+    "fig" => "figure",
+    "ch" => "chapter",
+    "p" => "page",
+    "pp" => "pages",
+    // Months before a year, e.g. "Jan. 2024", since they can be names, e.g. "I met Jan." Months before a
+    // day are part of a date, e.g. "Jan. 5". "May" is never abbreviated.
+    "jan" => "January",
+    "feb" => "February",
+    "mar" => "March",
+    "apr" => "April",
+    "jun" => "June",
+    "jul" => "July",
+    "aug" => "August",
+    "sep" => "September",
+    "sept" => "September",
+    "oct" => "October",
+    "nov" => "November",
+    "dec" => "December",
+};
+
+/// Full names of the months, indexed by month number minus one.
+pub static MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// Maps abbreviated month names to their month number.
+pub static MONTH_ABBREVIATIONS: phf::Map<&'static str, usize> = phf_map! {
+    "Jan" => 1,
+    "Feb" => 2,
+    "Mar" => 3,
+    "Apr" => 4,
+    "Jun" => 6,
+    "Jul" => 7,
+    "Aug" => 8,
+    "Sep" => 9,
+    "Sept" => 9,
+    "Oct" => 10,
+    "Nov" => 11,
+    "Dec" => 12,
+};
+
+/// Words that, when directly preceding a four digit number, indicate that the number is a year.
+/// The words are lowercase.
+pub static YEAR_PREPOSITIONS: phf::Set<&'static str> = phf_set! {
+    "in",
+    "since",
+    "by",
+    "from",
+    "until",
+    "till",
+    "before",
+    "after",
+    "during",
+    "circa",
+    "year",
+};
+
+/// Era designations that, when directly following a four digit number, indicate that the number is a year.
+pub static YEAR_ERAS: phf::Set<&'static str> = phf_set! {
+    "AD",
+    "BC",
+    "BCE",
+    "CE",
+};
+
+/// Words (lowercase) that, when directly preceding a number, indicate that the number is a code that is read
+/// in digit groups, e.g. "ZIP 02139", "PIN 1234" or "Call 555-1234".
+pub static CODE_WORDS: phf::Set<&'static str> = phf_set! {
+    "zip",
+    "postcode",
+    "code",
+    "id",
+    "pin",
+    "account",
+    "phone",
+    "tel",
+    "fax",
+    "call",
+    "dial",
+};
+
+/// Words (lowercase) that, when directly preceding a Roman numeral, indicate that it is read as a cardinal
+/// number, e.g. "Chapter IV" or "World War II".
+pub static ROMAN_NUMERAL_TITLE_WORDS: phf::Set<&'static str> = phf_set! {
+    "chapter",
+    "part",
+    "book",
+    "volume",
+    "act",
+    "scene",
+    "episode",
+    "war",
+    "bowl",
+    "phase",
+    "stage",
+    "level",
+    "type",
+    "class",
+    "article",
+    "section",
+    "appendix",
+};
+
+/// Determiners, prepositions and conjunctions (lowercase). They are not names, even if they are capitalized,
+/// so a Roman numeral after them is not a regnal number, e.g. "The IV drip".
+pub static FUNCTION_WORDS: phf::Set<&'static str> = phf_set! {
+    "the", "a", "an", "this", "that", "these", "those", "my", "your", "his", "her", "its", "our", "their",
+    "no", "any", "some", "every", "each", "in", "on", "at", "by", "for", "with", "from", "to", "of", "into",
+    "via", "per", "and", "or", "but",
+};
+
+/// Top level domains (lowercase) that mark a text like "docs.rs" as a URL. Domains that are common english
+/// words, such as "in" or "it", are left out, since they are more likely a missing space after a sentence.
+pub static TOP_LEVEL_DOMAINS: phf::Set<&'static str> = phf_set! {
+    "com", "org", "net", "edu", "gov", "mil", "int", "info", "biz", "io", "dev", "app", "ai", "co", "rs",
+    "ly", "gg", "tv", "fm", "xyz", "uk", "de", "fr", "es", "nl", "eu", "ch", "se", "dk", "fi", "pl", "cz",
+    "ru", "jp", "cn", "kr", "ca", "au", "nz", "br",
+};
+
+/// Short URL segments (lowercase) that are pronounced as words. Other segments with up to three letters
+/// are spelled, e.g. "io" or "rs".
+pub static WEB_WORDS: phf::Set<&'static str> = phf_set! {
+    "com", "org", "net", "gov", "biz", "app", "dev", "web", "pay", "box", "hub", "map", "new", "top",
+};
+
+/// Words for the symbols in URLs and email addresses.
+pub static WEB_SYMBOLS: phf::Map<char, &'static str> = phf_map! {
+    '.' => "dot",
+    '/' => "slash",
+    '@' => "at",
+    '-' => "dash",
+    '_' => "underscore",
+    ':' => "colon",
+    '?' => "question mark",
+    '=' => "equals",
+    '&' => "and",
+    '#' => "hash",
+    '~' => "tilde",
+    '+' => "plus",
+    '%' => "percent",
+};
+
+/// Words for symbols that are always spoken.
+pub static SYMBOL_WORDS: phf::Map<&'static str, &'static str> = phf_map! {
+    "&" => "and",
+    "+" => "plus",
+    "%" => "percent",
+};
+
+/// Words for math operators, which are only spoken between two operands, e.g. "a > b" or "2*3".
+pub static OPERATOR_WORDS: phf::Map<&'static str, &'static str> = phf_map! {
+    "=" => "equals",
+    "==" => "equals",
+    "<" => "less than",
+    ">" => "greater than",
+    "<=" => "less than or equal to",
+    ">=" => "greater than or equal to",
+    "*" => "times",
+    "^" => "to the power of",
+};
+
+/// Symbols that are not spoken if they are not part of an expression, e.g. "*" in "*important*" or
+/// "#" in "#hashtag". They can be dropped with `EnPhonemizerOptions::drop_decorative_symbols`.
+pub static DECORATIVE_SYMBOLS: phf::Set<&'static str> = phf_set! {
+    "*", "^", "~", "|", "\\", "#", "<", ">", "=",
+};
+
 // src: https://gist.github.com/dirkgr/6349f379740880209475
 /// List mapping some unicode characters to their closest counterparts in ASCII
 pub static UNICODE2ASCII: phf::Map<char, &'static str> = phf_map! {
@@ -0,0 +1,121 @@
+//! Verbalization of amounts of money, such as "$5.99" or "€1.2M".
+
+use super::constants::{CurrencyUnitNames, CURRENCIES, MAGNITUDE_SUFFIXES};
+use super::numbers;
+
+/// Verbalize an amount of money as matched by `Token::Currency`, e.g. "$5.99" becomes
+/// "five dollars and ninety-nine cents", and "€1.2M" becomes "one point two million euros".
+pub fn verbalize(amount: &str) -> String {
+    verbalize_with_magnitude(amount, None)
+}
+
+/// Same as [`verbalize`], but with a magnitude word that follows the amount in the text, e.g.
+/// "$5" followed by "million" becomes "five million dollars".
+pub fn verbalize_with_magnitude(amount: &str, magnitude: Option<&str>) -> String {
+    let (symbol, amount) = split_symbol(amount);
+    let names = CURRENCIES
+        .get(&symbol)
+        .expect("Currency symbol not found in CURRENCIES dict, this should not be possible.");
+
+    // Split off the magnitude suffix, e.g. "1.2M" -> ("1.2", "M")
+    let digits_end = amount
+        .rfind(|c: char| c.is_ascii_digit())
+        .map_or(0, |i| i + 1);
+    let (amount, suffix) = amount.split_at(digits_end);
+    let magnitude = magnitude.or_else(|| MAGNITUDE_SUFFIXES.get(suffix).copied());
+
+    let amount = amount.replace(',', "");
+
+    // Large amounts are always read as a decimal number with the plural unit
+    if let Some(magnitude) = magnitude {
+        return format!(
+            "{} {} {}",
+            numbers::decimal(&amount),
+            magnitude,
+            names.unit.1
+        );
+    }
+
+    let (units, subunits) = amount.split_once('.').unwrap_or((&amount, ""));
+    match (subunits.len(), names.subunit) {
+        (0, _) => count(units, names.unit),
+        (2, Some(subunit_names)) => {
+            let units = trim_leading_zeros(units);
+            let subunits = trim_leading_zeros(subunits);
+            match (units, subunits) {
+                ("0", "0") => format!("zero {}", names.unit.1),
+                ("0", _) => count(subunits, subunit_names),
+                (_, "0") => count(units, names.unit),
+                _ => format!(
+                    "{} and {}",
+                    count(units, names.unit),
+                    count(subunits, subunit_names)
+                ),
+            }
+        }
+        _ => format!("{} {}", numbers::decimal(&amount), names.unit.1),
+    }
+}
+
+/// Split the currency symbol from the amount. The symbol can be before or after the amount.
+fn split_symbol(amount: &str) -> (char, &str) {
+    let first = amount.chars().next().expect("Amount can not be empty.");
+    if CURRENCIES.contains_key(&first) {
+        return (first, &amount[first.len_utf8()..]);
+    }
+
+    let last = amount
+        .chars()
+        .next_back()
+        .expect("Amount can not be empty.");
+    (last, &amount[..amount.len() - last.len_utf8()])
+}
+
+/// Verbalize a number of currency units, with the singular or plural name as appropriate.
+fn count(digits: &str, names: CurrencyUnitNames) -> String {
+    let digits = trim_leading_zeros(digits);
+    let name = if digits == "1" { names.0 } else { names.1 };
+    format!("{} {}", numbers::cardinal(digits), name)
+}
+
+/// Remove leading zeros from a digit sequence. If only zeros remain, "0" is returned.
+fn trim_leading_zeros(digits: &str) -> &str {
+    match digits.trim_start_matches('0') {
+        "" => "0",
+        trimmed => trimmed,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dollars_and_cents_test() {
+        assert_eq!(verbalize("$5.99"), "five dollars and ninety-nine cents");
+        assert_eq!(verbalize("$1.01"), "one dollar and one cent");
+        assert_eq!(verbalize("$0.99"), "ninety-nine cents");
+        assert_eq!(verbalize("$3.00"), "three dollars");
+        assert_eq!(verbalize("$1,000"), "one thousand dollars");
+    }
+
+    #[test]
+    fn other_currencies_test() {
+        assert_eq!(verbalize("£20"), "twenty pounds");
+        assert_eq!(verbalize("£1.50"), "one pound and fifty pence");
+        assert_eq!(verbalize("20€"), "twenty euros");
+        assert_eq!(verbalize("¥1"), "one yen");
+        assert_eq!(verbalize("¥1.5"), "one point five yen");
+    }
+
+    #[test]
+    fn magnitude_test() {
+        assert_eq!(verbalize("€1.2M"), "one point two million euros");
+        assert_eq!(verbalize("$5k"), "five thousand dollars");
+        assert_eq!(verbalize("$3bn"), "three billion dollars");
+        assert_eq!(
+            verbalize_with_magnitude("$1", Some("million")),
+            "one million dollars"
+        );
+    }
+}
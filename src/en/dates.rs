@@ -0,0 +1,170 @@
+//! Verbalization of dates, such as "01.02.2024" or "March 3rd, 2024".
+
+use super::constants::{MONTHS, MONTH_ABBREVIATIONS};
+use super::numbers;
+use super::phonemizer::DateOrder;
+
+/// Get the number (1 to 12) of a month from its full name ("March") or its abbreviation ("Mar").
+/// Names are case sensitive.
+pub fn month_number(name: &str) -> Option<usize> {
+    MONTHS
+        .iter()
+        .position(|&month| month == name)
+        .map(|i| i + 1)
+        .or_else(|| MONTH_ABBREVIATIONS.get(name).copied())
+}
+
+/// Verbalize a date as matched by `Token::Date`, e.g. "03/03/2024" and "March 3rd, 2024" both become
+/// "March third, twenty twenty-four". The order of day and month in numeric dates is only used if it
+/// can not be inferred from the date itself. Dates that are written day first keep that order,
+/// e.g. "3 March 2024" becomes "the third of March, twenty twenty-four".
+pub fn verbalize(date: &str, order: DateOrder) -> String {
+    let verbalized = if date.contains(' ') {
+        verbalize_written(date)
+    } else {
+        verbalize_numeric(date, order)
+    };
+
+    // Invalid dates, e.g. "45/67/2024", are read as separate numbers
+    verbalized.unwrap_or_else(|| {
+        date.split(|c: char| !c.is_ascii_digit())
+            .filter(|part| !part.is_empty())
+            .map(numbers::cardinal)
+            .collect::<Vec<String>>()
+            .join(" ")
+    })
+}
+
+fn verbalize_numeric(date: &str, order: DateOrder) -> Option<String> {
+    let parts: Vec<&str> = date.split(['.', '/', '-']).collect();
+
+    let (year, month, day) = match parts.as_slice() {
+        // ISO 8601
+        [year, month, day] if year.len() == 4 => (*year, month.parse().ok()?, day.parse().ok()?),
+        [first, second, year] => {
+            let first: usize = first.parse().ok()?;
+            let second: usize = second.parse().ok()?;
+            let (month, day) = match order {
+                DateOrder::MonthFirst if first > 12 => (second, first),
+                DateOrder::MonthFirst => (first, second),
+                DateOrder::DayFirst if second > 12 => (first, second),
+                DateOrder::DayFirst => (second, first),
+            };
+            (*year, month, day)
+        }
+        _ => return None,
+    };
+
+    Some(format!(
+        "{} {}, {}",
+        month_name(month)?,
+        day_ordinal(day)?,
+        year_words(year)
+    ))
+}
+
+fn verbalize_written(date: &str) -> Option<String> {
+    let parts: Vec<&str> = date
+        .split([' ', ','])
+        .map(|part| part.trim_end_matches('.'))
+        .filter(|part| !part.is_empty())
+        .collect();
+
+    let month = month_name(parts.iter().find_map(|part| month_number(part))?)?;
+    let mut numbers = parts
+        .iter()
+        .filter(|part| part.starts_with(|c: char| c.is_ascii_digit()));
+    let day = numbers.next()?;
+    let day = day_ordinal(
+        day.trim_end_matches(|c: char| c.is_ascii_alphabetic())
+            .parse()
+            .ok()?,
+    )?;
+    let year = numbers.next().map(|year| year_words(year));
+
+    let mut verbalized = if month_number(parts[0]).is_some() {
+        format!("{} {}", month, day)
+    } else {
+        format!("the {} of {}", day, month)
+    };
+    if let Some(year) = year {
+        verbalized.push_str(", ");
+        verbalized.push_str(&year);
+    }
+
+    Some(verbalized)
+}
+
+fn month_name(month: usize) -> Option<&'static str> {
+    MONTHS.get(month.checked_sub(1)?).copied()
+}
+
+fn day_ordinal(day: usize) -> Option<String> {
+    (1..=31)
+        .contains(&day)
+        .then(|| numbers::ordinal_from_cardinal(&numbers::cardinal_from_u64(day as u64)))
+}
+
+/// Four digit years are read as years, two digit years as cardinal numbers, e.g. "24" is
+/// "twenty-four" and "05" is "oh five".
+fn year_words(year: &str) -> String {
+    numbers::year(year).unwrap_or_else(|| numbers::cardinal(year))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numeric_date_test() {
+        let expected = "March third, twenty twenty-four";
+        assert_eq!(verbalize("03/03/2024", DateOrder::MonthFirst), expected);
+        assert_eq!(verbalize("3.3.2024", DateOrder::DayFirst), expected);
+        assert_eq!(verbalize("2024-03-03", DateOrder::DayFirst), expected);
+        assert_eq!(
+            verbalize("12-25-05", DateOrder::MonthFirst),
+            "December twenty-fifth, oh five"
+        );
+    }
+
+    #[test]
+    fn numeric_date_order_test() {
+        assert_eq!(
+            verbalize("01/02/2024", DateOrder::MonthFirst),
+            "January second, twenty twenty-four"
+        );
+        assert_eq!(
+            verbalize("01/02/2024", DateOrder::DayFirst),
+            "February first, twenty twenty-four"
+        );
+        // Unambiguous dates ignore the order
+        assert_eq!(
+            verbalize("13/02/2024", DateOrder::MonthFirst),
+            "February thirteenth, twenty twenty-four"
+        );
+    }
+
+    #[test]
+    fn written_date_test() {
+        assert_eq!(
+            verbalize("March 3rd, 2024", DateOrder::MonthFirst),
+            "March third, twenty twenty-four"
+        );
+        assert_eq!(
+            verbalize("Dec. 24", DateOrder::MonthFirst),
+            "December twenty-fourth"
+        );
+        assert_eq!(
+            verbalize("3rd of March 1999", DateOrder::MonthFirst),
+            "the third of March, nineteen ninety-nine"
+        );
+    }
+
+    #[test]
+    fn invalid_date_test() {
+        assert_eq!(
+            verbalize("45/67/2024", DateOrder::MonthFirst),
+            "forty-five sixty-seven two thousand twenty-four"
+        );
+    }
+}
@@ -0,0 +1,60 @@
+//! Verbalization of numbers that are read in digit groups, such as phone numbers, postal codes and IDs.
+
+use super::constants::{DIGIT2WORD, ONES};
+use super::phonemizer::ZeroReading;
+
+/// Verbalize a number digit by digit, with a pause (comma) between groups of digits, e.g. "(555) 123-4567"
+/// becomes "five five five, one two three, four five six seven". A leading "+" is read as "plus".
+pub fn verbalize(number: &str, zero: ZeroReading) -> String {
+    let groups: Vec<String> = number
+        .split(|c: char| !c.is_ascii_digit())
+        .filter(|group| !group.is_empty())
+        .map(|group| read_digits(group, zero))
+        .collect();
+
+    let words = groups.join(", ");
+    if number.starts_with('+') {
+        format!("plus {}", words)
+    } else {
+        words
+    }
+}
+
+fn read_digits(digits: &str, zero: ZeroReading) -> String {
+    digits
+        .chars()
+        .map(|c| match (c, zero) {
+            ('0', ZeroReading::Zero) => ONES[0],
+            _ => DIGIT2WORD
+                .get(&c)
+                .expect("Digit not found in DIGIT2WORD dict, this should not be possible."),
+        })
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn phone_number_test() {
+        assert_eq!(
+            verbalize("(555) 123-4567", ZeroReading::Oh),
+            "five five five, one two three, four five six seven"
+        );
+        assert_eq!(
+            verbalize("+1 800 555 0199", ZeroReading::Zero),
+            "plus one, eight zero zero, five five five, zero one nine nine"
+        );
+    }
+
+    #[test]
+    fn code_test() {
+        assert_eq!(verbalize("02139", ZeroReading::Oh), "oh two one three nine");
+        assert_eq!(
+            verbalize("90210-1234", ZeroReading::Zero),
+            "nine zero two one zero, one two three four"
+        );
+    }
+}
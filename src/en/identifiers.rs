@@ -0,0 +1,70 @@
+//! Splitting of identifiers from source code, such as "TranscriptionLookup", "phonemize_word" or "HTTPServer".
+
+/// Split an identifier at case transitions, underscores and digit boundaries, e.g. "getUserID2" becomes
+/// ["get", "User", "ID", "2"] and "phonemize_word" becomes ["phonemize", "_", "word"]. Every underscore is
+/// returned as a separate part.
+pub fn split(identifier: &str) -> Vec<&str> {
+    let chars: Vec<(usize, char)> = identifier.char_indices().collect();
+    let mut parts = Vec::new();
+    let mut start = 0;
+
+    for (i, &(offset, c)) in chars.iter().enumerate() {
+        if c == '_' {
+            if start < offset {
+                parts.push(&identifier[start..offset]);
+            }
+            parts.push(&identifier[offset..offset + c.len_utf8()]);
+            start = offset + c.len_utf8();
+            continue;
+        }
+
+        let Some(&(_, previous)) = i.checked_sub(1).map(|i| &chars[i]) else {
+            continue;
+        };
+        let next = chars.get(i + 1).map(|&(_, next)| next);
+
+        let boundary = (previous.is_lowercase() && c.is_uppercase())
+            // The last uppercase letter of an acronym starts the next word, e.g. "HTTPServer"
+            || (previous.is_uppercase() && c.is_uppercase() && next.is_some_and(char::is_lowercase))
+            || (previous.is_alphabetic() && c.is_ascii_digit())
+            || (previous.is_ascii_digit() && c.is_alphabetic());
+        if boundary && start < offset {
+            parts.push(&identifier[start..offset]);
+            start = offset;
+        }
+    }
+    if start < identifier.len() {
+        parts.push(&identifier[start..]);
+    }
+
+    parts
+}
+
+/// Whether the word looks like an identifier, i.e. it contains underscores or case transitions,
+/// e.g. "phonemize_word" or "TranscriptionLookup".
+pub fn is_identifier(word: &str) -> bool {
+    word.chars().any(char::is_alphabetic) && split(word).len() > 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_test() {
+        assert_eq!(split("TranscriptionLookup"), ["Transcription", "Lookup"]);
+        assert_eq!(split("phonemize_word"), ["phonemize", "_", "word"]);
+        assert_eq!(split("HTTPServer"), ["HTTP", "Server"]);
+        assert_eq!(split("getUserID2"), ["get", "User", "ID", "2"]);
+        assert_eq!(split("__init__"), ["_", "_", "init", "_", "_"]);
+    }
+
+    #[test]
+    fn is_identifier_test() {
+        assert!(is_identifier("getUser"));
+        assert!(is_identifier("snake_case"));
+        assert!(!is_identifier("Hello"));
+        assert!(!is_identifier("NASA"));
+        assert!(!is_identifier("_"));
+    }
+}
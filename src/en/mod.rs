@@ -1,5 +1,17 @@
 //! This module contains everything needed for (US) english phonemization.
+mod abbreviations;
+mod clitics;
+mod compounds;
 mod constants;
+mod currency;
+mod dates;
+mod digit_groups;
+mod identifiers;
+mod numbers;
+mod roman_numerals;
+mod suffixes;
+mod times;
+mod web;
 mod word2ipa;
 mod tokenizer;
 pub mod phonemizer;
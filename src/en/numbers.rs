@@ -0,0 +1,353 @@
+//! Verbalization of numbers, i.e. conversion of digit strings to english words.
+//! The resulting words are meant to be looked up in the transcription dicts afterwards.
+
+use super::constants::{DIGIT2WORD, IRREGULAR_ORDINALS, ONES, SCALES, TENS, UNITS};
+
+/// Numbers with more digits than this are read digit by digit, since there are no
+/// scale words available for them.
+const MAX_CARDINAL_DIGITS: usize = 3 * SCALES.len();
+
+/// Verbalize a sequence of ASCII digits as a cardinal number, e.g. "1995" becomes
+/// "one thousand nine hundred ninety-five".
+///
+/// Sequences with leading zeros (e.g. "007") and sequences that are too long are read digit by digit.
+pub fn cardinal(digits: &str) -> String {
+    if digits.len() > MAX_CARDINAL_DIGITS || (digits.len() > 1 && digits.starts_with('0')) {
+        return digit_by_digit(digits);
+    }
+
+    match digits.parse::<u64>() {
+        Ok(number) => cardinal_from_u64(number),
+        Err(_) => digit_by_digit(digits),
+    }
+}
+
+/// Verbalize a number as a cardinal number.
+pub fn cardinal_from_u64(number: u64) -> String {
+    if number == 0 {
+        return String::from(ONES[0]);
+    }
+
+    let mut groups: Vec<String> = Vec::new();
+    let mut remaining = number;
+    let mut scale = 0;
+    while remaining > 0 {
+        let group = (remaining % 1000) as usize;
+        if group != 0 {
+            let mut words = below_thousand(group);
+            if scale > 0 {
+                words.push(' ');
+                words.push_str(SCALES[scale]);
+            }
+            groups.push(words);
+        }
+        remaining /= 1000;
+        scale += 1;
+    }
+
+    groups.reverse();
+    groups.join(" ")
+}
+
+/// Verbalize a sequence of ASCII digits as an ordinal number, e.g. "21" becomes "twenty-first"
+/// and "101" becomes "one hundred first".
+pub fn ordinal(digits: &str) -> String {
+    ordinal_from_cardinal(&cardinal(digits))
+}
+
+/// Convert verbalized cardinal number words to their ordinal form, by changing the last word,
+/// e.g. "twenty-one" becomes "twenty-first".
+pub fn ordinal_from_cardinal(words: &str) -> String {
+    let split_at = words.rfind([' ', '-']).map_or(0, |i| i + 1);
+    let (head, last) = words.split_at(split_at);
+
+    let last = if let Some(&irregular) = IRREGULAR_ORDINALS.get(last) {
+        String::from(irregular)
+    } else if let Some(stem) = last.strip_suffix('y') {
+        format!("{}ieth", stem)
+    } else {
+        format!("{}th", last)
+    };
+
+    format!("{}{}", head, last)
+}
+
+/// Verbalize a four digit number as a year, e.g. "1995" becomes "nineteen ninety-five", "1905" becomes
+/// "nineteen oh five" and "2010" becomes "twenty ten". Returns `None` if the digits are not a plausible year.
+pub fn year(digits: &str) -> Option<String> {
+    if digits.len() != 4 || digits.starts_with('0') {
+        return None;
+    }
+    let number: usize = digits.parse().ok()?;
+
+    let century = number / 100;
+    let rest = number % 100;
+    let words = if number.is_multiple_of(1000) || (century.is_multiple_of(10) && rest < 10) {
+        // e.g. 2000 or 2005
+        cardinal_from_u64(number as u64)
+    } else if rest == 0 {
+        format!("{} hundred", below_hundred(century))
+    } else if rest < 10 {
+        format!("{} oh {}", below_hundred(century), ONES[rest])
+    } else {
+        format!("{} {}", below_hundred(century), below_hundred(rest))
+    };
+
+    Some(words)
+}
+
+/// Pluralize verbalized number words, e.g. "nineteen ninety" becomes "nineteen nineties". This
+/// is used for decades and centuries ("1990s", "1800s").
+pub fn plural(words: &str) -> String {
+    if let Some(stem) = words.strip_suffix('y') {
+        format!("{}ies", stem)
+    } else if words.ends_with('x') {
+        format!("{}es", words)
+    } else {
+        format!("{}s", words)
+    }
+}
+
+/// Verbalize a decimal number, e.g. "123.45" becomes "one hundred twenty-three point four five".
+/// The integer part is read as a cardinal number, the fractional part digit by digit. Thousands
+/// separators are ignored, e.g. "1,000" is read as "one thousand".
+pub fn decimal(number: &str) -> String {
+    let number = number.replace(',', "");
+    match number.split_once('.') {
+        Some((integer, fraction)) => {
+            format!("{} point {}", cardinal(integer), digit_by_digit(fraction))
+        }
+        None => cardinal(&number),
+    }
+}
+
+/// Verbalize a percentage, e.g. "45%" becomes "forty-five percent".
+pub fn percentage(percentage: &str) -> String {
+    format!("{} percent", decimal(percentage.trim_end_matches('%')))
+}
+
+/// Verbalize a fraction, e.g. "3/4" becomes "three quarters" and "1/3" becomes "one third".
+/// Fractions with large denominators are read with "over", e.g. "355/113" becomes
+/// "three hundred fifty-five over one hundred thirteen".
+pub fn fraction(fraction: &str) -> String {
+    let Some((numerator, denominator)) = fraction.split_once('/') else {
+        return cardinal(fraction);
+    };
+    // Not a fraction, but common enough
+    if fraction == "24/7" {
+        return String::from("twenty-four seven");
+    }
+
+    let singular = numerator.trim_start_matches('0') == "1";
+    let denominator_words = match denominator.parse::<u64>() {
+        Ok(2) if singular => String::from("half"),
+        Ok(2) => String::from("halves"),
+        Ok(4) if singular => String::from("quarter"),
+        Ok(4) => String::from("quarters"),
+        Ok(3..=100) if singular => ordinal(denominator),
+        Ok(3..=100) => format!("{}s", ordinal(denominator)),
+        _ => return format!("{} over {}", cardinal(numerator), cardinal(denominator)),
+    };
+
+    format!("{} {}", cardinal(numerator), denominator_words)
+}
+
+/// Verbalize a range of numbers, e.g. "10-20" becomes "ten to twenty". If both numbers are
+/// plausible years, they are read as years, e.g. "1990-2000" becomes "nineteen ninety to two thousand".
+pub fn range(range: &str) -> String {
+    let Some((start, end)) = range.split_once('-') else {
+        return decimal(range);
+    };
+
+    match (year(start), year(end)) {
+        (Some(start), Some(end)) => format!("{} to {}", start, end),
+        _ => format!("{} to {}", decimal(start), decimal(end)),
+    }
+}
+
+/// Verbalize a multiplier, e.g. "2x" becomes "two times".
+pub fn multiplier(multiplier: &str) -> String {
+    format!("{} times", decimal(multiplier.trim_end_matches(['x', 'X'])))
+}
+
+/// Verbalize a number with a unit of measurement, e.g. "5 km" becomes "five kilometers" and "1kg"
+/// becomes "one kilogram". Units that are not found in [`UNITS`] are kept as they are.
+pub fn measurement(measurement: &str) -> String {
+    let number_end = measurement
+        .find(|c: char| !c.is_ascii_digit() && c != '.' && c != ',')
+        .unwrap_or(measurement.len());
+    let (number, unit) = measurement.split_at(number_end);
+    let unit = unit.trim_start();
+
+    let unit_words = match UNITS.get(unit) {
+        Some((singular, _)) if number == "1" => singular,
+        Some((_, plural)) => plural,
+        None => unit,
+    };
+
+    format!("{} {}", decimal(number), unit_words)
+}
+
+/// Read a sequence of ASCII digits one digit at a time, e.g. "105" becomes "one oh five".
+pub fn digit_by_digit(digits: &str) -> String {
+    digits
+        .chars()
+        .map(|c| {
+            *DIGIT2WORD
+                .get(&c)
+                .expect("Digit not found in DIGIT2WORD dict, this should not be possible.")
+        })
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+/// Verbalize a number between 1 and 999.
+fn below_thousand(number: usize) -> String {
+    let hundreds = number / 100;
+    let rest = number % 100;
+
+    let mut words = String::new();
+    if hundreds > 0 {
+        words.push_str(ONES[hundreds]);
+        words.push_str(" hundred");
+    }
+    if rest > 0 {
+        if !words.is_empty() {
+            words.push(' ');
+        }
+        words.push_str(&below_hundred(rest));
+    }
+    words
+}
+
+/// Verbalize a number between 0 and 99. Compound numbers are hyphenated, e.g. "ninety-five".
+fn below_hundred(number: usize) -> String {
+    match number {
+        0..20 => String::from(ONES[number]),
+        _ if number.is_multiple_of(10) => String::from(TENS[number / 10]),
+        _ => format!("{}-{}", TENS[number / 10], ONES[number % 10]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cardinal_small_numbers_test() {
+        assert_eq!(cardinal("0"), "zero");
+        assert_eq!(cardinal("7"), "seven");
+        assert_eq!(cardinal("13"), "thirteen");
+        assert_eq!(cardinal("40"), "forty");
+        assert_eq!(cardinal("95"), "ninety-five");
+    }
+
+    #[test]
+    fn cardinal_large_numbers_test() {
+        assert_eq!(cardinal("100"), "one hundred");
+        assert_eq!(cardinal("1995"), "one thousand nine hundred ninety-five");
+        assert_eq!(cardinal("1000001"), "one million one");
+        assert_eq!(
+            cardinal("250300017"),
+            "two hundred fifty million three hundred thousand seventeen"
+        );
+    }
+
+    #[test]
+    fn cardinal_digit_fallback_test() {
+        assert_eq!(cardinal("007"), "oh oh seven");
+        assert_eq!(
+            cardinal("1234567890123456"),
+            "one two three four five six seven eight nine oh one two three four five six"
+        );
+    }
+
+    #[test]
+    fn ordinal_test() {
+        assert_eq!(ordinal("1"), "first");
+        assert_eq!(ordinal("2"), "second");
+        assert_eq!(ordinal("12"), "twelfth");
+        assert_eq!(ordinal("20"), "twentieth");
+        assert_eq!(ordinal("21"), "twenty-first");
+        assert_eq!(ordinal("101"), "one hundred first");
+        assert_eq!(ordinal("1000"), "one thousandth");
+    }
+
+    #[test]
+    fn year_test() {
+        assert_eq!(year("1995").unwrap(), "nineteen ninety-five");
+        assert_eq!(year("1905").unwrap(), "nineteen oh five");
+        assert_eq!(year("1800").unwrap(), "eighteen hundred");
+        assert_eq!(year("2000").unwrap(), "two thousand");
+        assert_eq!(year("2007").unwrap(), "two thousand seven");
+        assert_eq!(year("2010").unwrap(), "twenty ten");
+        assert_eq!(year("0995"), None);
+        assert_eq!(year("19950"), None);
+    }
+
+    #[test]
+    fn plural_test() {
+        assert_eq!(plural("eighteen hundred"), "eighteen hundreds");
+        assert_eq!(plural("nineteen ninety"), "nineteen nineties");
+        assert_eq!(plural("nineteen oh six"), "nineteen oh sixes");
+    }
+
+    #[test]
+    fn percentage_test() {
+        assert_eq!(percentage("45%"), "forty-five percent");
+        assert_eq!(percentage("2.5%"), "two point five percent");
+    }
+
+    #[test]
+    fn fraction_test() {
+        assert_eq!(fraction("3/4"), "three quarters");
+        assert_eq!(fraction("1/3"), "one third");
+        assert_eq!(fraction("1/2"), "one half");
+        assert_eq!(fraction("5/8"), "five eighths");
+        assert_eq!(fraction("2/20"), "two twentieths");
+        assert_eq!(
+            fraction("355/113"),
+            "three hundred fifty-five over one hundred thirteen"
+        );
+    }
+
+    #[test]
+    fn range_test() {
+        assert_eq!(range("10-20"), "ten to twenty");
+        assert_eq!(range("1.5-2"), "one point five to two");
+        assert_eq!(range("1990-2000"), "nineteen ninety to two thousand");
+    }
+
+    #[test]
+    fn multiplier_test() {
+        assert_eq!(multiplier("2x"), "two times");
+        assert_eq!(multiplier("1.5X"), "one point five times");
+    }
+
+    #[test]
+    fn measurement_test() {
+        assert_eq!(measurement("5 km"), "five kilometers");
+        assert_eq!(measurement("1kg"), "one kilogram");
+        assert_eq!(measurement("30°C"), "thirty degrees Celsius");
+        assert_eq!(measurement("60 mph"), "sixty miles per hour");
+        assert_eq!(measurement("2.5GB"), "two point five gigabytes");
+        assert_eq!(
+            measurement("1,500 km"),
+            "one thousand five hundred kilometers"
+        );
+    }
+
+    #[test]
+    fn decimal_test() {
+        assert_eq!(
+            decimal("123.45"),
+            "one hundred twenty-three point four five"
+        );
+        assert_eq!(decimal("0.05"), "zero point oh five");
+        assert_eq!(decimal("1,000"), "one thousand");
+        assert_eq!(
+            decimal("12,345.5"),
+            "twelve thousand three hundred forty-five point five"
+        );
+    }
+}
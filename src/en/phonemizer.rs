@@ -1,131 +1,1772 @@
+use std::collections::{HashMap, VecDeque};
+use std::ops::Range;
+
 use anyhow::{anyhow, Context, Result};
 use phonetisaurus_g2p::PhonetisaurusModel;
-use tokenizers::{normalizers::NFKC, NormalizedString, Normalizer};
+use tokenizers::{
+    normalizer::Range as OffsetRange, normalizers::NFKC, NormalizedString, Normalizer,
+};
 
-use crate::en::{constants::DIGIT2WORD, word2ipa::WORD2IPA_EN};
+use crate::en::{
+    abbreviations, clitics, compounds, currency, dates, digit_groups, identifiers, numbers,
+    roman_numerals,
+    suffixes::{self, Suffix},
+    times, web,
+    word2ipa::WORD2IPA_EN,
+};
 use phonemoro_common::{TranscriptionEntry, TranscriptionLookup};
 
-use super::constants::UNICODE2ASCII;
+use super::constants::{
+    CODE_WORDS, DECORATIVE_SYMBOLS, FUNCTION_WORDS, OPERATOR_WORDS, ROMAN_NUMERAL_TITLE_WORDS,
+    SCALES, SYMBOL_WORDS, UNICODE2ASCII, YEAR_ERAS, YEAR_PREPOSITIONS,
+};
 use super::tokenizer::{EnTokenizer, Token, TokenContext};
 
 static PHONETISAURUS_MODEL_BIN: &[u8] = include_bytes!("data/model.fst");
 
+/// Size of the window of text that [`PhonemeIter`] takes sentences from, in bytes.
+const ITER_WINDOW_SIZE: usize = 4096;
+
+/// Acronyms up to this length are only looked up with their exact case, see
+/// `EnPhonemizer::look_up_acronym_transcription`.
+const MAX_CASE_SENSITIVE_ACRONYM_LENGTH: usize = 3;
+
+/// Order of day and month in numeric dates.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DateOrder {
+    /// US order, e.g. "01/02/2024" is January 2nd.
+    #[default]
+    MonthFirst,
+    /// E.g. "01/02/2024" is February 1st.
+    DayFirst,
+}
+
+/// How times without "am" or "pm" are read.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TimeReading {
+    /// Read as time of day, e.g. "10:30" is "ten thirty".
+    #[default]
+    Clock,
+    /// Read as duration, e.g. "3:45:10" is "three hours forty-five minutes ten seconds".
+    Duration,
+}
+
+/// How the digit zero is read in numbers that are read digit by digit, such as phone numbers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ZeroReading {
+    /// E.g. "911-0800" is "nine one one, oh eight oh oh".
+    #[default]
+    Oh,
+    /// E.g. "911-0800" is "nine one one, zero eight zero zero".
+    Zero,
+}
+
+/// How acronyms and initialisms, i.e. words in all caps like "NASA" or "FBI", are phonemized.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AcronymPolicy {
+    /// Pronounce the acronym as a word if it is in the dicts, otherwise spell it, e.g. "NASA" is
+    /// pronounced as a word, and "FBI" is spelled.
+    #[default]
+    LookUpOrSpell,
+    /// Always spell acronyms letter by letter.
+    Spell,
+    /// Treat acronyms like any other word, i.e. use the FST if they are not in the dicts.
+    Word,
+}
+
+/// How identifiers from source code, such as "TranscriptionLookup" or "phonemize_word", are phonemized.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum IdentifierPolicy {
+    /// Treat identifiers like any other word.
+    #[default]
+    Word,
+    /// Split identifiers at case transitions and underscores, and phonemize the parts separately. Underscores
+    /// are not spoken.
+    Split,
+    /// Same as [`IdentifierPolicy::Split`], but underscores are read as "underscore".
+    SplitReadUnderscores,
+}
+
+/// Options that change how text is phonemized.
+#[derive(Debug, Clone, Default)]
+pub struct EnPhonemizerOptions {
+    /// Order used for numeric dates where day and month are ambiguous, e.g. "01/02/2024".
+    pub date_order: DateOrder,
+    /// Whether times like "3:45" are read as time of day or as duration.
+    pub time_reading: TimeReading,
+    /// Whether zeros in phone numbers, postal codes and IDs are read as "oh" or "zero".
+    pub zero_reading: ZeroReading,
+    /// How words in all caps, such as "NASA" or "FBI", are phonemized.
+    pub acronym_policy: AcronymPolicy,
+    /// Whether symbols that are not spoken, such as "*" in "*important*", are removed from the output
+    /// instead of being kept as they are.
+    pub drop_decorative_symbols: bool,
+    /// Whether identifiers such as "getUserID" or "phonemize_word" are split into their parts.
+    pub identifier_policy: IdentifierPolicy,
+}
+
+/// Where the phonemes of a word come from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TranscriptionSource {
+    /// The word was found in the dicts. `dict` is the name of the dict, e.g. "us_gold", and `variant` is the
+    /// variant that was used if the word is a homograph.
+    Dictionary {
+        dict: &'static str,
+        variant: Option<&'static str>,
+    },
+    /// The word is missing from the dicts, but its stem was found after removing an inflectional suffix,
+    /// e.g. "unfriended" is "unfriend" and "-ed".
+    Suffix { stem: String, suffix: &'static str },
+    /// The word is missing from the dicts, but it is a compound of words that were found, e.g. "smartwatch"
+    /// is "smart" and "watch".
+    Compound { parts: Vec<String> },
+    /// The word was phonemized with the FST.
+    Fst,
+    /// The word is missing from the dicts, but it is a word with a clitic, e.g. "law's" is "law" and "'s".
+    /// `source` states where the phonemes of the word without the clitic come from.
+    Clitic {
+        stem: String,
+        clitic: String,
+        source: Box<TranscriptionSource>,
+    },
+}
+
+/// The phonemes of a single word, together with their source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WordTranscription {
+    pub phonemes: String,
+    pub source: TranscriptionSource,
+}
+
+impl WordTranscription {
+    /// Attach a clitic to the transcription of the word without it, see [`TranscriptionSource::Clitic`].
+    /// The transcription is returned unchanged if the clitic is empty.
+    fn with_clitic(self, stem: &str, clitic: &str) -> Self {
+        if clitic.is_empty() {
+            return self;
+        }
+
+        WordTranscription {
+            phonemes: clitics::attach(&self.phonemes, clitic),
+            source: TranscriptionSource::Clitic {
+                stem: String::from(stem),
+                clitic: String::from(clitic),
+                source: Box::new(self.source),
+            },
+        }
+    }
+}
+
+/// How the phonemes of a token were produced.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TokenSource {
+    /// The token is a word that was phonemized on its own.
+    Word(TranscriptionSource),
+    /// The token was converted to words before phonemization, e.g. numbers, dates, abbreviations or
+    /// symbols like "&".
+    Verbalized { words: String },
+    /// The token is an acronym that was spelled letter by letter, e.g. "FBI".
+    Spelled,
+    /// The token was split into parts that were phonemized separately, e.g. hyphenated words like
+    /// "well-known" or identifiers like "getUserID".
+    Split { parts: Vec<String> },
+    /// The token is not spoken and was kept as it is (or dropped), e.g. punctuation and whitespace.
+    Passthrough,
+}
+
+impl TokenSource {
+    /// Whether the phonemes of the token are spoken words, as opposed to punctuation or whitespace.
+    pub fn is_spoken(&self) -> bool {
+        *self != TokenSource::Passthrough
+    }
+}
+
+/// A phonemized token, see [`EnPhonemizer::phonemize_traced`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TracedToken {
+    /// Byte range of the token in the input text.
+    pub span: Range<usize>,
+    /// Text of the token after normalization.
+    pub graphemes: String,
+    pub phonemes: String,
+    pub source: TokenSource,
+}
+
+/// A phonemized sentence.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sentence {
+    /// Byte range of the sentence in the input text.
+    pub span: Range<usize>,
+    /// Text of the sentence, as in the input text.
+    pub text: String,
+    pub phonemes: String,
+}
+
+/// The phonemes of a token, aligned with the input text. See [`EnPhonemizer::phonemize_aligned`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PhonemeAlignment {
+    /// Byte range of the phonemes in [`AlignedPhonemes::phonemes`].
+    pub phonemes_span: Range<usize>,
+    /// Byte range of the token in the input text.
+    pub span: Range<usize>,
+    /// Char range of the token in the input text.
+    pub char_span: Range<usize>,
+    /// Whether the token is spoken, as opposed to punctuation or whitespace.
+    pub spoken: bool,
+}
+
+/// A phonemized text, where the phonemes of each token are aligned with the input text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlignedPhonemes {
+    /// The phonemes, same as returned by [`EnPhonemizer::phonemize`].
+    pub phonemes: String,
+    /// One alignment per token, in order.
+    pub alignments: Vec<PhonemeAlignment>,
+}
+
+/// An item of a phonemized text, see [`EnPhonemizer::phonemize_iter`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PhonemeItem {
+    /// The phonemes of a spoken token, e.g. a word or a number. Spoken tokens that directly follow each
+    /// other, such as "MP" and "3" in "MP3", are separate items.
+    Word(String),
+    /// Punctuation and symbols that are not spoken, e.g. "," or "...". Dropped decorative symbols are skipped.
+    Punctuation(String),
+    Whitespace(String),
+    /// The end of a sentence.
+    SentenceBoundary,
+}
+
 /// Phonemizer struct.
 #[derive(Debug)]
 pub struct EnPhonemizer {
     normalizer: NFKC, // Maybe make this more dynamic with some kind of impl / dyn / where
     fallback_phonemizer: PhonetisaurusModel,
+    options: EnPhonemizerOptions,
+    /// Abbreviations registered at runtime, with keys as returned by `abbreviations::lookup_key`.
+    custom_abbreviations: HashMap<String, String>,
 }
 
 impl EnPhonemizer {
-    /// Create a new phonemizer.
+    /// Create a new phonemizer with the default options.
     pub fn new() -> Result<Self> {
+        Self::with_options(EnPhonemizerOptions::default())
+    }
+
+    /// Create a new phonemizer with the given options.
+    pub fn with_options(options: EnPhonemizerOptions) -> Result<Self> {
         Ok(EnPhonemizer {
-            normalizer: NFKC::default(),
+            normalizer: NFKC,
             fallback_phonemizer: PhonetisaurusModel::try_from(PHONETISAURUS_MODEL_BIN)?, // TODO: find out how to only check this during compile time
+            options,
+            custom_abbreviations: HashMap::new(),
         })
     }
 
+    /// Register an abbreviation that is expanded during phonemization, e.g. "approx." and "approximately".
+    /// The trailing dot is optional and case is ignored. Registered abbreviations take precedence over the
+    /// built-in ones.
+    pub fn add_abbreviation(&mut self, abbreviation: &str, expansion: &str) {
+        self.custom_abbreviations.insert(
+            abbreviations::lookup_key(abbreviation),
+            String::from(expansion),
+        );
+    }
+
     /// Phonemize a text. For each word, a dictionary lookup is performed, and if nothing is found, the word is
     /// phonemized with a finite state transducer trained using Phonetisaurus.
     pub fn phonemize(&self, text: &str) -> Result<String> {
         let normalized = self.normalize(text)?;
-        let tokens = self.tokenize(normalized.as_str())?;
+        let tokens = self.tokenize(normalized.get())?;
         let phonemes = self.tokens2phonemes(&tokens)?;
 
         Ok(phonemes)
     }
 
+    /// Phonemize a text sentence by sentence. Dots in abbreviations ("Dr."), decimal numbers and URLs
+    /// do not end sentences. Each sentence has its byte range and text in the original input.
+    pub fn phonemize_sentences(&self, text: &str) -> Result<Vec<Sentence>> {
+        self.sentences2phonemes(text, true)
+    }
+
+    /// Phonemize a text sentence by sentence, see [`EnPhonemizer::phonemize_sentences`]. If `include_last`
+    /// is false, the last sentence is skipped, since it might not be complete yet.
+    fn sentences2phonemes(&self, text: &str, include_last: bool) -> Result<Vec<Sentence>> {
+        let normalized = self.normalize(text)?;
+        let tokens = self.tokenize(normalized.get())?;
+
+        let mut ranges = EnTokenizer::split_sentences(&tokens);
+        if !include_last {
+            ranges.pop();
+        }
+
+        ranges
+            .into_iter()
+            .map(|range| {
+                let sentence_tokens = &tokens[range];
+                let span = original_span(&normalized, sentence_tokens)
+                    .context("Sentence could not be aligned with the input text.")?;
+
+                Ok(Sentence {
+                    text: String::from(&text[span.clone()]),
+                    span,
+                    phonemes: self.tokens2phonemes(sentence_tokens)?,
+                })
+            })
+            .collect()
+    }
+
+    /// Phonemize a text and trace where the phonemes of each token come from, e.g. from which dict, or
+    /// whether the FST was used. This is useful to find out why a word is mispronounced. Tokens that are
+    /// read together, such as "$5 million", are returned as one token. Concatenating the phonemes does not
+    /// give exactly the output of [`EnPhonemizer::phonemize`], since spoken tokens are separated by spaces
+    /// there.
+    pub fn phonemize_traced(&self, text: &str) -> Result<Vec<TracedToken>> {
+        let normalized = self.normalize(text)?;
+        let tokens = self.tokenize(normalized.get())?;
+
+        let mut traced = Vec::new();
+        let mut index = 0;
+        while index < tokens.len() {
+            let (phonemes, source, consumed) = self.token2phonemes(&tokens, index)?;
+            let token_group = &tokens[index..index + consumed];
+            let span = original_span(&normalized, token_group)
+                .context("Token could not be aligned with the input text.")?;
+
+            traced.push(TracedToken {
+                span,
+                graphemes: token_group.iter().map(|tc| tc.slice).collect(),
+                phonemes,
+                source,
+            });
+            index += consumed;
+        }
+
+        Ok(traced)
+    }
+
+    /// Phonemize a text lazily. When items are requested, the next sentences are lexed and phonemized from
+    /// a window of a few kilobytes of the text, so phonemization can be stopped early, and the tokens and
+    /// phonemes of long texts are not held in memory as a whole. Only sentences longer than the window make
+    /// it grow. Sentences are phonemized as in [`EnPhonemizer::phonemize_sentences`].
+    pub fn phonemize_iter<'a>(&'a self, text: &'a str) -> PhonemeIter<'a> {
+        PhonemeIter {
+            phonemizer: self,
+            remaining: text,
+            items: VecDeque::new(),
+            window_size: ITER_WINDOW_SIZE,
+        }
+    }
+
+    /// Phonemize a text and align the phonemes of each token with the input text, e.g. to highlight the
+    /// word that is currently spoken. Spans refer to the original input, not to the normalized text, and
+    /// are given both in bytes and in chars.
+    pub fn phonemize_aligned(&self, text: &str) -> Result<AlignedPhonemes> {
+        let mut phonemes = String::new();
+        let mut alignments = Vec::new();
+        let mut char_offsets = CharOffsets::new(text);
+        let mut previous_spoken = false;
+
+        for traced in self.phonemize_traced(text)? {
+            let spoken = traced.source.is_spoken();
+            // Same separation as in `tokens2phonemes`
+            if previous_spoken && spoken {
+                phonemes.push(' ');
+            }
+
+            let phonemes_start = phonemes.len();
+            phonemes.push_str(&traced.phonemes);
+            let char_span =
+                char_offsets.convert(traced.span.start)..char_offsets.convert(traced.span.end);
+
+            alignments.push(PhonemeAlignment {
+                phonemes_span: phonemes_start..phonemes.len(),
+                span: traced.span,
+                char_span,
+                spoken,
+            });
+            previous_spoken = spoken;
+        }
+
+        Ok(AlignedPhonemes {
+            phonemes,
+            alignments,
+        })
+    }
+
+    /// Phonemize the sentences of a text and append their items to `items`. If `include_last` is false, the
+    /// last sentence and the whitespace before it are skipped, since the sentence might not be complete yet.
+    /// Returns the number of bytes of the text that were phonemized.
+    fn sentences2items(
+        &self,
+        text: &str,
+        include_last: bool,
+        items: &mut VecDeque<PhonemeItem>,
+    ) -> Result<usize> {
+        let normalized = self.normalize(text)?;
+        let tokens = self.tokenize(normalized.get())?;
+
+        let mut ranges = EnTokenizer::split_sentences(&tokens);
+        let end = if include_last {
+            tokens.len()
+        } else {
+            ranges.pop();
+            match ranges.last() {
+                Some(range) => range.end,
+                None => return Ok(0),
+            }
+        };
+
+        // Whitespace between sentences is not part of any sentence
+        let push_whitespace = |items: &mut VecDeque<PhonemeItem>, tokens: &[TokenContext]| {
+            items.extend(
+                tokens
+                    .iter()
+                    .map(|tc| PhonemeItem::Whitespace(String::from(tc.slice))),
+            )
+        };
+
+        let mut position = 0;
+        for range in ranges {
+            push_whitespace(items, &tokens[position..range.start]);
+
+            let sentence_tokens = &tokens[range.clone()];
+            let mut index = 0;
+            while index < sentence_tokens.len() {
+                let (phonemes, source, consumed) = self.token2phonemes(sentence_tokens, index)?;
+                if source.is_spoken() {
+                    items.push_back(PhonemeItem::Word(phonemes));
+                } else if sentence_tokens[index].token == Token::Whitespace {
+                    items.push_back(PhonemeItem::Whitespace(phonemes));
+                } else if !phonemes.is_empty() {
+                    // Dropped decorative symbols are skipped
+                    items.push_back(PhonemeItem::Punctuation(phonemes));
+                }
+                index += consumed;
+            }
+            items.push_back(PhonemeItem::SentenceBoundary);
+
+            position = range.end;
+        }
+        push_whitespace(items, &tokens[position..end]);
+
+        if end == tokens.len() {
+            return Ok(text.len());
+        }
+        original_span(&normalized, &tokens[..end])
+            .map(|span| span.end)
+            .context("Sentence could not be aligned with the input text.")
+    }
+
     // NORMALIZATION
-    fn normalize(&self, text: &str) -> Result<String> {
+    /// Normalize the text. The returned string keeps track of the alignment with the original text.
+    fn normalize(&self, text: &str) -> Result<NormalizedString> {
         let mut text = NormalizedString::from(text);
         self.normalizer
             .normalize(&mut text)
             .map_err(|e| anyhow!(e))?; // converts Box<dyn Error + Send + Sync> to anyhow error
 
         // Try to normalize some unicode characters to their closes ascii counterparts,
-        // for better compatibility with the lookup dicts.
-        let normalized_string = text.get().chars().fold(String::new(), |mut acc, c| {
-            if let Some(&replacement) = UNICODE2ASCII.get(&c) {
-                acc.push_str(replacement);
-            } else {
-                acc.push(c);
-            }
-            acc
-        });
+        // for better compatibility with the lookup dicts. The first replacement character
+        // replaces the original one, all others are new.
+        let transformed: Vec<(char, isize)> = text
+            .get()
+            .chars()
+            .flat_map(|c| match UNICODE2ASCII.get(&c) {
+                Some(&replacement) => replacement
+                    .chars()
+                    .enumerate()
+                    .map(|(i, r)| (r, isize::from(i > 0)))
+                    .collect::<Vec<(char, isize)>>(),
+                None => vec![(c, 0)],
+            })
+            .collect();
+        text.transform(transformed, 0);
 
-        Ok(normalized_string)
+        Ok(text)
     }
 
     // TOKENIZATION
     fn tokenize<'a>(&self, text: &'a str) -> Result<Vec<TokenContext<'a>>> {
-        EnTokenizer::tokenize(text)
+        EnTokenizer::tokenize_with_abbreviations(text, &|abbreviation| {
+            self.custom_abbreviations
+                .contains_key(&abbreviations::lookup_key(abbreviation))
+        })
     }
 
     // TRANSCRIPTION
-    fn look_up_transcription(&self, graphemes: &str) -> Option<&str> {
-        if let Some(transcription) = WORD2IPA_EN.lookup_loose(graphemes) {
-            return Some(match transcription {
-                TranscriptionEntry::Single(ph) => ph,
-                TranscriptionEntry::Multiple(variant_mapping) => variant_mapping
-                    .get("DEFAULT")
-                    .expect("DEFAULT variant not found."),
-            });
-            // TODO: Instead of using DEFAULT, use a homograph disambiguation algorithm / model
-            // Possible starts:
-            // - Viterbi Algorithm:
-            //      - https://github.com/nkaush/pos-tagging
-            //      - https://github.com/ian-nai/viterbi_pos_tagger
-            // - Perceptron Tagger:
-            //      - https://github.com/shubham0204/postagger.rs
-            //      - Celosia
-            // - BERT:
-            //      - https://docs.rs/rust-bert/latest/rust_bert/pipelines/pos_tagging/
-            // - Custom model
+    fn look_up_transcription(&self, graphemes: &str) -> Option<WordTranscription> {
+        if let Some((dict, entry)) = WORD2IPA_EN.lookup_loose_with_dict(graphemes) {
+            return Some(dict_transcription(dict, entry));
+        }
+
+        // Words with clitics, e.g. "Smith's", are looked up without the clitic
+        let (stem, clitic) = clitics::split(graphemes)?;
+        let (dict, entry) = WORD2IPA_EN.lookup_loose_with_dict(stem)?;
+        Some(dict_transcription(dict, entry).with_clitic(stem, clitic))
+    }
+
+    /// Look up the transcription of an acronym. Short acronyms are only looked up with their exact case,
+    /// since e.g. "US" or "IT" would otherwise be found as the words "us" and "it".
+    fn look_up_acronym_transcription(&self, acronym: &str) -> Option<WordTranscription> {
+        if acronym.chars().count() > MAX_CASE_SENSITIVE_ACRONYM_LENGTH {
+            return self.look_up_transcription(acronym);
+        }
+
+        WORD2IPA_EN
+            .lookup_exact_with_dict(acronym)
+            .map(|(dict, entry)| dict_transcription(dict, entry))
+    }
+
+    /// Whether the short all caps word at `index` is a word in all caps text, e.g. "THE" in "THE END", rather
+    /// than an acronym. This is the case with [`AcronymPolicy::LookUpOrSpell`], if the word is in the dicts in
+    /// lowercase, and a neighboring word is in all caps too.
+    fn is_word_in_all_caps_text(&self, tokens: &[TokenContext], index: usize) -> bool {
+        let word = tokens[index].slice;
+
+        self.options.acronym_policy == AcronymPolicy::LookUpOrSpell
+            && word.chars().count() <= MAX_CASE_SENSITIVE_ACRONYM_LENGTH
+            && [previous_word(tokens, index), next_word(tokens, index)]
+                .into_iter()
+                .flatten()
+                .any(is_acronym)
+            && WORD2IPA_EN.lookup_exact(&word.to_lowercase()).is_some()
+    }
+
+    /// Phonemize a single word. The dicts are queried first, then the dicts are queried for the word
+    /// without inflectional suffixes (e.g. "unfriended" is looked up as "unfriend"), then for the parts
+    /// of the word if it is a compound (e.g. "smartwatch"), and if nothing is found, the word is
+    /// phonemized with the FST. Capitalized words are not split into compounds, since they are often names,
+    /// e.g. "Sandoval" is not "sand" and "oval". The returned transcription states which of these was used.
+    pub fn transcribe_word(&self, word: &str) -> Result<WordTranscription> {
+        if let Some(transcription) = self.look_up_transcription(word) {
+            return Ok(transcription);
         }
 
-        None
+        // Clitics are removed first and attached afterwards, e.g. "unfriended's". The FST also does
+        // not handle them well.
+        let (stem, clitic) = clitics::split(word).unwrap_or((word, ""));
+
+        if let Some((inflection_stem, suffix, phonemes)) = self.look_up_inflected(stem) {
+            return Ok(WordTranscription {
+                phonemes,
+                source: TranscriptionSource::Suffix {
+                    stem: inflection_stem,
+                    suffix: suffix.as_str(),
+                },
+            }
+            .with_clitic(stem, clitic));
+        }
+
+        if !stem.starts_with(char::is_uppercase)
+            && let Some(parts) =
+                compounds::split(stem, |part| WORD2IPA_EN.lookup_loose(part).is_some())
+        {
+            let part_phonemes: Vec<String> = parts
+                .iter()
+                .filter_map(|part| WORD2IPA_EN.lookup_loose(part))
+                .map(|entry| String::from(default_variant(entry).0))
+                .collect();
+
+            return Ok(WordTranscription {
+                phonemes: join_with_head_stress(&part_phonemes),
+                source: TranscriptionSource::Compound {
+                    parts: parts.into_iter().map(String::from).collect(),
+                },
+            }
+            .with_clitic(stem, clitic));
+        }
+
+        // phonemize unknown word with FST
+        let fst_phonemization = self
+            .fallback_phonemizer
+            .phonemize_word(stem)
+            .with_context(|| format!("Phonemization of word {} with the FST failed.", word))?;
+
+        Ok(WordTranscription {
+            phonemes: fst_phonemization.phonemes,
+            source: TranscriptionSource::Fst,
+        }
+        .with_clitic(stem, clitic))
     }
 
-    fn tokens2phonemes(&self, tokens: &Vec<TokenContext>) -> Result<String> {
-        let results: Vec<String> = tokens
+    /// Look up an inflected word by its stem, e.g. "unfriended" as "unfriend". Returns the stem, the suffix
+    /// and the phonemes of the whole word.
+    fn look_up_inflected(&self, word: &str) -> Option<(String, Suffix, String)> {
+        suffixes::candidates(word)
+            .into_iter()
+            .find_map(|(stem, suffix)| {
+                let (stem_phonemes, _) = WORD2IPA_EN.lookup_loose(&stem).map(default_variant)?;
+                let phonemes = suffixes::attach(stem_phonemes, suffix);
+                Some((stem, suffix, phonemes))
+            })
+    }
+
+    /// Phonemize a single word, see [`EnPhonemizer::transcribe_word`].
+    fn word2phonemes(&self, word: &str) -> Result<String> {
+        Ok(self.transcribe_word(word)?.phonemes)
+    }
+
+    /// Phonemize an acronym according to the [`AcronymPolicy`]. Spelled acronyms are pronounced as one word,
+    /// with secondary stress on all letters except the last, e.g. "FBI" becomes "ˌɛfbˌiˈI".
+    fn acronym2phonemes(&self, acronym: &str) -> Result<(String, TokenSource)> {
+        let transcription = match self.options.acronym_policy {
+            AcronymPolicy::Word => Some(self.transcribe_word(acronym)?),
+            AcronymPolicy::LookUpOrSpell => self.look_up_acronym_transcription(acronym),
+            AcronymPolicy::Spell => None,
+        };
+        if let Some(transcription) = transcription {
+            return Ok((
+                transcription.phonemes,
+                TokenSource::Word(transcription.source),
+            ));
+        }
+
+        let letters = acronym
+            .chars()
+            .map(|letter| self.word2phonemes(&letter.to_string()))
+            .collect::<Result<Vec<String>>>()?;
+
+        Ok((join_with_head_stress(&letters), TokenSource::Spelled))
+    }
+
+    /// Phonemize an identifier part by part according to the [`IdentifierPolicy`], e.g. "getUserID" is read
+    /// as "get user I D".
+    fn identifier2phonemes(&self, identifier: &str) -> Result<(String, TokenSource)> {
+        let read_underscores =
+            self.options.identifier_policy == IdentifierPolicy::SplitReadUnderscores;
+        let parts: Vec<&str> = identifiers::split(identifier)
+            .into_iter()
+            .filter(|&part| part != "_" || read_underscores)
+            .collect();
+
+        let phonemes = parts
             .iter()
-            .map(|tc| {
-                // query dicts
-                let dict_lookup_result: Option<String> = match tc.token {
-                    Token::Word => self.look_up_transcription(&tc.slice).map(String::from),
-                    Token::DigitSequence => {
-                        let mut full_string = String::new();
-                        full_string.push_str(" ");
-                        tc.slice.chars().for_each(|c| {
-                            let graphemes = DIGIT2WORD.get(&c).expect(
-                                "Digit not found in DIGIT2WORD dict, this should not be possible.",
-                            );
-                            full_string.push_str(self.look_up_transcription(graphemes).unwrap());
-                            full_string.push(' ');
-                        });
-
-                        Some(full_string)
-                    }
-                    _ => Some(String::from(tc.slice)),
+            .map(|&part| match part {
+                "_" => self.word2phonemes("underscore"),
+                part if part.starts_with(|c: char| c.is_ascii_digit()) => {
+                    self.words2phonemes(&numbers::cardinal(part))
+                }
+                part if is_acronym(part) => Ok(self.acronym2phonemes(part)?.0),
+                part => self.word2phonemes(part),
+            })
+            .collect::<Result<Vec<String>>>()?;
+
+        Ok((
+            phonemes.join(" "),
+            TokenSource::Split {
+                parts: parts.into_iter().map(String::from).collect(),
+            },
+        ))
+    }
+
+    /// Phonemize a hyphenated compound, e.g. "well-known" or "COVID-19". The whole compound is looked up
+    /// first, otherwise the parts are phonemized separately and joined without the hyphen, with secondary
+    /// stress on all parts except the last one (the head). Acronyms are spelled if needed, and digits are
+    /// read as a number.
+    fn compound2phonemes(&self, compound: &str) -> Result<(String, TokenSource)> {
+        if let Some(transcription) = self.look_up_transcription(compound) {
+            return Ok((
+                transcription.phonemes,
+                TokenSource::Word(transcription.source),
+            ));
+        }
+
+        let parts: Vec<&str> = compound
+            .split('-')
+            .filter(|part| !part.is_empty())
+            .collect();
+        let phonemes = parts
+            .iter()
+            .map(|&part| {
+                if part.starts_with(|c: char| c.is_ascii_digit()) {
+                    self.words2phonemes(&numbers::cardinal(part))
+                } else if is_acronym(part) {
+                    Ok(self.acronym2phonemes(part)?.0)
+                } else {
+                    self.word2phonemes(part)
+                }
+            })
+            .collect::<Result<Vec<String>>>()?;
+
+        Ok((
+            join_with_head_stress(&phonemes),
+            TokenSource::Split {
+                parts: parts.into_iter().map(String::from).collect(),
+            },
+        ))
+    }
+
+    /// Phonemize words separated by whitespace, such as the output of a verbalizer.
+    /// Hyphenated words (e.g. "ninety-five") are phonemized as compounds, and commas directly
+    /// after a word are kept.
+    fn words2phonemes(&self, words: &str) -> Result<String> {
+        let phonemes = words
+            .split_whitespace()
+            .map(|word| {
+                let (word, comma) = match word.strip_suffix(',') {
+                    Some(word) => (word, ","),
+                    None => (word, ""),
+                };
+                let phonemes = if word.contains('-') {
+                    self.compound2phonemes(word)?.0
+                } else {
+                    self.word2phonemes(word)?
                 };
 
-                if let Some(phonemes) = dict_lookup_result {
-                    return phonemes;
+                Ok(phonemes + comma)
+            })
+            .collect::<Result<Vec<String>>>()?;
+
+        Ok(phonemes.join(" "))
+    }
+
+    /// Verbalize the digit sequence at `index`. Four digit numbers are read as years if the neighboring
+    /// tokens suggest it, e.g. "in 1995", "May 2024" or "1800s". Returns the words and the number of tokens
+    /// consumed.
+    fn verbalize_digit_sequence(&self, tokens: &[TokenContext], index: usize) -> (String, usize) {
+        let digits = tokens[index].slice;
+
+        // Numbers with thousands separators, e.g. "1,000", are neither codes nor years
+        if digits.contains(',') {
+            return (numbers::decimal(digits), 1);
+        }
+
+        if is_code(tokens, index) {
+            return (
+                digit_groups::verbalize(digits, self.options.zero_reading),
+                1,
+            );
+        }
+
+        if let Some(year) = numbers::year(digits) {
+            // Decades and centuries, i.e. "1990s" or "1990's"
+            match (tokens.get(index + 1), tokens.get(index + 2)) {
+                (Some(next), _) if next.token == Token::Word && next.slice == "s" => {
+                    return (numbers::plural(&year), 2);
                 }
+                (Some(next), Some(after_next))
+                    if next.slice == "'"
+                        && after_next.token == Token::Word
+                        && after_next.slice == "s" =>
+                {
+                    return (numbers::plural(&year), 3);
+                }
+                _ => {}
+            }
+
+            let preceded_by_preposition = previous_word(tokens, index)
+                .is_some_and(|word| YEAR_PREPOSITIONS.contains(word.to_lowercase().as_str()));
+            // Month names without a day, e.g. "March 2020" or "Dec. 2024"
+            let preceded_by_month = tokens[..index]
+                .iter()
+                .rev()
+                .find(|tc| tc.token != Token::Whitespace)
+                .is_some_and(|tc| {
+                    matches!(tc.token, Token::Word | Token::AbbreviatedWord)
+                        && dates::month_number(tc.slice.trim_end_matches('.')).is_some()
+                });
+            let followed_by_era =
+                next_word(tokens, index).is_some_and(|word| YEAR_ERAS.contains(word));
+
+            if preceded_by_preposition || preceded_by_month || followed_by_era {
+                return (year, 1);
+            }
+        }
+
+        (numbers::cardinal(digits), 1)
+    }
+
+    /// Verbalize the amount of money at `index`. A magnitude word directly after the amount is moved
+    /// before the currency name, i.e. "$5 million" is read as "five million dollars". Returns the words
+    /// and the number of tokens consumed.
+    fn verbalize_currency(&self, tokens: &[TokenContext], index: usize) -> (String, usize) {
+        let amount = tokens[index].slice;
+
+        if amount.ends_with(|c: char| c.is_ascii_digit())
+            && tokens
+                .get(index + 1)
+                .is_some_and(|tc| tc.token == Token::Whitespace)
+            && let Some(magnitude) = tokens
+                .get(index + 2)
+                .filter(|tc| tc.token == Token::Word && SCALES[1..].contains(&tc.slice))
+        {
+            return (
+                currency::verbalize_with_magnitude(amount, Some(magnitude.slice)),
+                3,
+            );
+        }
 
-                // phonemize unknown word with FST
-                let fst_phonemization = self
-                    .fallback_phonemizer
-                    .phonemize_word(tc.slice)
-                    .with_context(|| {
-                        format!("Phonemization of word {} with the FST failed.", tc.slice)
-                    })
-                    .unwrap();
+        (currency::verbalize(amount), 1)
+    }
+
+    /// Verbalize the word at `index` if it is a Roman numeral in a name or title, e.g. "VIII" in "Henry VIII"
+    /// becomes "the eighth", and "II" in "World War II" becomes "two". The name or title word has to be
+    /// capitalized, so that e.g. "V" in "part V" is not read. Returns `None` for all other words.
+    fn verbalize_roman_numeral(&self, tokens: &[TokenContext], index: usize) -> Option<String> {
+        let numeral = tokens[index].slice;
+        let previous = previous_word(tokens, index)?;
+        if !previous.starts_with(char::is_uppercase) {
+            return None;
+        }
+
+        if ROMAN_NUMERAL_TITLE_WORDS.contains(previous.to_lowercase().as_str()) {
+            return roman_numerals::cardinal(numeral);
+        }
+
+        // Regnal numbers follow names, e.g. not in "The IV drip"
+        let previous_index = tokens[..index]
+            .iter()
+            .rposition(|tc| tc.token != Token::Whitespace)?;
+        if !is_name(tokens, previous_index) {
+            return None;
+        }
+
+        roman_numerals::regnal(numeral)
+    }
+
+    /// Expand the abbreviation at `index`, e.g. "Dr." in "Dr. Smith" becomes "Doctor".
+    fn expand_abbreviation<'a>(&'a self, tokens: &[TokenContext<'a>], index: usize) -> &'a str {
+        let abbreviation = tokens[index].slice;
 
-                fst_phonemization.phonemes
+        if let Some(expansion) = self
+            .custom_abbreviations
+            .get(&abbreviations::lookup_key(abbreviation))
+        {
+            return expansion;
+        }
+
+        abbreviations::expand(abbreviation, next_word(tokens, index))
+            .unwrap_or_else(|| abbreviation.trim_end_matches('.'))
+    }
+
+    /// Phonemize the output of a verbalizer, see [`EnPhonemizer::words2phonemes`].
+    fn verbalization2phonemes(&self, words: impl Into<String>) -> Result<(String, TokenSource)> {
+        let words = words.into();
+        Ok((
+            self.words2phonemes(&words)?,
+            TokenSource::Verbalized { words },
+        ))
+    }
+
+    /// Phonemize the token at `index`. Some tokens are read together with the following tokens, e.g.
+    /// "$5 million". Returns the phonemes, their source and the number of tokens consumed.
+    fn token2phonemes(
+        &self,
+        tokens: &[TokenContext],
+        index: usize,
+    ) -> Result<(String, TokenSource, usize)> {
+        let tc = &tokens[index];
+        let mut consumed = 1;
+
+        let (phonemes, source) = match tc.token {
+            Token::Word => match self.verbalize_roman_numeral(tokens, index) {
+                Some(words) => self.verbalization2phonemes(words)?,
+                None if is_acronym(tc.slice) && !self.is_word_in_all_caps_text(tokens, index) => {
+                    self.acronym2phonemes(tc.slice)?
+                }
+                None if self.options.identifier_policy != IdentifierPolicy::Word
+                    && identifiers::is_identifier(tc.slice)
+                    && self.look_up_transcription(tc.slice).is_none() =>
+                {
+                    self.identifier2phonemes(tc.slice)?
+                }
+                None => {
+                    let transcription = self.transcribe_word(tc.slice)?;
+                    (
+                        transcription.phonemes,
+                        TokenSource::Word(transcription.source),
+                    )
+                }
+            },
+            Token::HyphenatedWord => self.compound2phonemes(tc.slice)?,
+            Token::AbbreviatedWord => {
+                self.verbalization2phonemes(self.expand_abbreviation(tokens, index))?
+            }
+            Token::DigitSequence => {
+                let (words, n) = self.verbalize_digit_sequence(tokens, index);
+                consumed = n;
+                self.verbalization2phonemes(words)?
+            }
+            Token::DecimalNumber => self.verbalization2phonemes(numbers::decimal(tc.slice))?,
+            Token::Date => {
+                self.verbalization2phonemes(dates::verbalize(tc.slice, self.options.date_order))?
+            }
+            Token::Time => {
+                self.verbalization2phonemes(times::verbalize(tc.slice, self.options.time_reading))?
+            }
+            Token::Percentage => self.verbalization2phonemes(numbers::percentage(tc.slice))?,
+            Token::Fraction => self.verbalization2phonemes(numbers::fraction(tc.slice))?,
+            Token::NumberRange if is_code(tokens, index) => self.verbalization2phonemes(
+                digit_groups::verbalize(tc.slice, self.options.zero_reading),
+            )?,
+            Token::NumberRange => self.verbalization2phonemes(numbers::range(tc.slice))?,
+            Token::Url | Token::Email => self.verbalization2phonemes(web::verbalize(tc.slice))?,
+            Token::PhoneNumber => self.verbalization2phonemes(digit_groups::verbalize(
+                tc.slice,
+                self.options.zero_reading,
+            ))?,
+            Token::Multiplier => self.verbalization2phonemes(numbers::multiplier(tc.slice))?,
+            Token::Measurement => self.verbalization2phonemes(numbers::measurement(tc.slice))?,
+            Token::Currency => {
+                let (words, n) = self.verbalize_currency(tokens, index);
+                consumed = n;
+                self.verbalization2phonemes(words)?
+            }
+            Token::Ordinal => {
+                // The suffix is always two ASCII characters long
+                let digits = &tc.slice[..tc.slice.len() - 2];
+                self.verbalization2phonemes(numbers::ordinal(digits))?
+            }
+            Token::Special => match verbalize_symbol(tokens, index) {
+                Some((words, n)) => {
+                    consumed = n;
+                    self.verbalization2phonemes(words)?
+                }
+                None if self.options.drop_decorative_symbols
+                    && DECORATIVE_SYMBOLS.contains(tc.slice) =>
+                {
+                    (String::new(), TokenSource::Passthrough)
+                }
+                None => (String::from(tc.slice), TokenSource::Passthrough),
+            },
+            _ => (String::from(tc.slice), TokenSource::Passthrough),
+        };
+
+        Ok((phonemes, source, consumed))
+    }
+
+    fn tokens2phonemes(&self, tokens: &[TokenContext]) -> Result<String> {
+        let mut phonemes = String::new();
+        let mut previous_spoken = false;
+
+        let mut index = 0;
+        while index < tokens.len() {
+            let (token_phonemes, source, consumed) = self.token2phonemes(tokens, index)?;
+            let spoken = source.is_spoken();
+
+            // Words and numbers can directly follow each other (e.g. "MP3"), so they need
+            // to be separated in the output.
+            if previous_spoken && spoken {
+                phonemes.push(' ');
+            }
+            phonemes.push_str(&token_phonemes);
+
+            index += consumed;
+            previous_spoken = spoken;
+        }
+
+        Ok(phonemes)
+    }
+}
+
+/// Lazy iterator over the items of a phonemized text, see [`EnPhonemizer::phonemize_iter`]. After an
+/// error, no more items are returned.
+#[derive(Debug)]
+pub struct PhonemeIter<'a> {
+    phonemizer: &'a EnPhonemizer,
+    /// Text that has not been phonemized yet.
+    remaining: &'a str,
+    /// Items of the phonemized sentences that have not been returned yet.
+    items: VecDeque<PhonemeItem>,
+    /// Size of the window of the remaining text that sentences are taken from, in bytes.
+    window_size: usize,
+}
+
+impl PhonemeIter<'_> {
+    /// Phonemize the complete sentences at the start of the remaining text. They are taken from a window
+    /// that ends at whitespace, so that no token is cut. The last sentence in the window is only phonemized
+    /// if the window contains the rest of the text, see [`EnStreamPhonemizer`]. If the window contains no
+    /// complete sentence, it is enlarged.
+    fn phonemize_next_sentences(&mut self) -> Result<()> {
+        let mut window_size = self.window_size;
+
+        loop {
+            let (window, is_rest) = if self.remaining.len() <= window_size {
+                (self.remaining, true)
+            } else {
+                let mut end = window_size;
+                while !self.remaining.is_char_boundary(end) {
+                    end -= 1;
+                }
+                match self.remaining[..end].rfind(char::is_whitespace) {
+                    Some(end) if end > 0 => (&self.remaining[..end], false),
+                    _ => {
+                        window_size *= 2;
+                        continue;
+                    }
+                }
+            };
+
+            let consumed = self
+                .phonemizer
+                .sentences2items(window, is_rest, &mut self.items)?;
+            if consumed > 0 {
+                self.remaining = &self.remaining[consumed..];
+                return Ok(());
+            }
+            window_size *= 2;
+        }
+    }
+}
+
+impl Iterator for PhonemeIter<'_> {
+    type Item = Result<PhonemeItem>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.items.is_empty() && !self.remaining.is_empty() {
+            if let Err(e) = self.phonemize_next_sentences() {
+                self.remaining = "";
+                return Some(Err(e));
+            }
+        }
+
+        self.items.pop_front().map(Ok)
+    }
+}
+
+/// Phonemizes text that arrives in chunks, e.g. the output of a language model, sentence by sentence.
+/// A sentence is returned once its delimiter is followed by whitespace and the next sentence has started,
+/// since e.g. "No." followed by a number does not end a sentence, and "example." might continue with "com".
+/// This way, words, numbers and addresses are never split between chunks.
+#[derive(Debug)]
+pub struct EnStreamPhonemizer<'a> {
+    phonemizer: &'a EnPhonemizer,
+    /// Text that was pushed, but not returned as part of a sentence yet.
+    buffer: String,
+    /// Byte offset of the buffer in all text pushed so far.
+    offset: usize,
+}
+
+impl<'a> EnStreamPhonemizer<'a> {
+    /// Create a new stream that uses the given phonemizer.
+    pub fn new(phonemizer: &'a EnPhonemizer) -> Self {
+        EnStreamPhonemizer {
+            phonemizer,
+            buffer: String::new(),
+            offset: 0,
+        }
+    }
+
+    /// Add a chunk of text and return the sentences that are complete now. The spans of the sentences
+    /// refer to all text pushed so far.
+    pub fn push(&mut self, chunk: &str) -> Result<Vec<Sentence>> {
+        self.buffer.push_str(chunk);
+        let mut sentences = self.phonemizer.sentences2phonemes(&self.buffer, false)?;
+
+        // Sentences whose delimiter is not followed by whitespace yet might continue in the next chunk
+        let complete = sentences
+            .iter()
+            .take_while(|sentence| {
+                self.buffer[sentence.span.end..].starts_with(char::is_whitespace)
+            })
+            .count();
+        sentences.truncate(complete);
+
+        Ok(self.consume(sentences))
+    }
+
+    /// Phonemize the remaining text, i.e. the last sentence, which does not need to end with a delimiter.
+    pub fn finish(mut self) -> Result<Vec<Sentence>> {
+        let sentences = self.phonemizer.sentences2phonemes(&self.buffer, true)?;
+
+        Ok(self.consume(sentences))
+    }
+
+    /// Remove the text of the returned sentences from the buffer, and move their spans from the buffer to
+    /// all text pushed so far.
+    fn consume(&mut self, mut sentences: Vec<Sentence>) -> Vec<Sentence> {
+        let Some(end) = sentences.last().map(|sentence| sentence.span.end) else {
+            return sentences;
+        };
+
+        for sentence in &mut sentences {
+            sentence.span = sentence.span.start + self.offset..sentence.span.end + self.offset;
+        }
+        self.buffer.drain(..end);
+        self.offset += end;
+
+        sentences
+    }
+}
+
+/// Get the transcription of a dict entry, and the variant that was used if the entry has more than one.
+/// For homographs, the DEFAULT variant is used.
+fn default_variant(entry: &TranscriptionEntry) -> (&'static str, Option<&'static str>) {
+    match entry {
+        TranscriptionEntry::Single(ph) => (ph, None),
+        TranscriptionEntry::Multiple(variant_mapping) => {
+            let (&variant, &ph) = variant_mapping
+                .get_entry("DEFAULT")
+                .expect("DEFAULT variant not found.");
+            (ph, Some(variant))
+        }
+    }
+    // TODO: Instead of using DEFAULT, use a homograph disambiguation algorithm / model
+    // Possible starts:
+    // - Viterbi Algorithm:
+    //      - https://github.com/nkaush/pos-tagging
+    //      - https://github.com/ian-nai/viterbi_pos_tagger
+    // - Perceptron Tagger:
+    //      - https://github.com/shubham0204/postagger.rs
+    //      - Celosia
+    // - BERT:
+    //      - https://docs.rs/rust-bert/latest/rust_bert/pipelines/pos_tagging/
+    // - Custom model
+}
+
+/// Get the transcription of an entry from the dict named `dict`.
+fn dict_transcription(dict: &'static str, entry: &TranscriptionEntry) -> WordTranscription {
+    let (phonemes, variant) = default_variant(entry);
+    WordTranscription {
+        phonemes: String::from(phonemes),
+        source: TranscriptionSource::Dictionary { dict, variant },
+    }
+}
+
+/// Get the byte range of the given tokens in the original text, i.e. before normalization. The tokens must
+/// be consecutive and not empty.
+fn original_span(normalized: &NormalizedString, tokens: &[TokenContext]) -> Option<Range<usize>> {
+    let normalized_span = tokens.first()?.span.start..tokens.last()?.span.end;
+    normalized.convert_offsets(OffsetRange::Normalized(normalized_span))
+}
+
+/// Converts byte offsets in a text to char offsets. Converting offsets in increasing order only counts the
+/// chars between them, instead of all chars from the start of the text.
+struct CharOffsets<'a> {
+    text: &'a str,
+    byte: usize,
+    char: usize,
+}
+
+impl<'a> CharOffsets<'a> {
+    fn new(text: &'a str) -> Self {
+        CharOffsets {
+            text,
+            byte: 0,
+            char: 0,
+        }
+    }
+
+    /// Convert a byte offset, which must be on a char boundary, to a char offset.
+    fn convert(&mut self, byte: usize) -> usize {
+        if byte < self.byte {
+            self.byte = 0;
+            self.char = 0;
+        }
+        self.char += self.text[self.byte..byte].chars().count();
+        self.byte = byte;
+        self.char
+    }
+}
+
+/// Join the phonemes of the parts of a word, e.g. the letters of an acronym or the parts of a compound. All
+/// parts except the last one (the head) get secondary stress, e.g. "ˈɛf", "bˈi" and "ˈI" become "ˌɛfbˌiˈI".
+fn join_with_head_stress(parts: &[String]) -> String {
+    let last = parts.len().saturating_sub(1);
+    parts
+        .iter()
+        .enumerate()
+        .map(|(i, phonemes)| {
+            if i < last {
+                phonemes.replace('ˈ', "ˌ")
+            } else {
+                phonemes.clone()
+            }
+        })
+        .collect()
+}
+
+/// Whether the word is an acronym or initialism, i.e. it has at least two letters, which are all uppercase,
+/// e.g. "NASA" or "FBI". Words with apostrophes, such as "I'M", are not acronyms.
+fn is_acronym(word: &str) -> bool {
+    word.chars().count() >= 2 && word.chars().all(|c| c.is_alphabetic() && c.is_uppercase())
+}
+
+/// Whether the token is a word or a number, i.e. it is not a symbol, punctuation or whitespace. Symbols are
+/// only spoken in some contexts, see [`verbalize_symbol`].
+fn is_operand(token: &Token) -> bool {
+    !matches!(
+        token,
+        Token::Special | Token::SentenceDelimiter | Token::Whitespace
+    )
+}
+
+/// Verbalize the symbol at `index`, e.g. "&" becomes "and", "#" in "#1" becomes "number" and ">" in "a > b"
+/// becomes "greater than". Returns the words and the number of tokens consumed, or `None` if the symbol is
+/// not spoken in this context.
+fn verbalize_symbol(tokens: &[TokenContext], index: usize) -> Option<(&'static str, usize)> {
+    let symbol = tokens[index].slice;
+    let next = tokens.get(index + 1);
+
+    if let Some(&words) = SYMBOL_WORDS.get(symbol) {
+        return Some((words, 1));
+    }
+    match symbol {
+        "#" if next.is_some_and(|tc| tc.token == Token::DigitSequence) => {
+            return Some(("number", 1));
+        }
+        "~" if next_non_whitespace(tokens, index).is_some_and(|tc| {
+            matches!(
+                tc.token,
+                Token::DigitSequence
+                    | Token::DecimalNumber
+                    | Token::Currency
+                    | Token::Percentage
+                    | Token::Measurement
+            )
+        }) =>
+        {
+            return Some(("about", 1));
+        }
+        _ => {}
+    }
+
+    // Two character operators, e.g. "<="
+    let (operator, length) = match next {
+        Some(tc) if OPERATOR_WORDS.contains_key(&format!("{}{}", symbol, tc.slice)) => {
+            (format!("{}{}", symbol, tc.slice), 2)
+        }
+        _ => (String::from(symbol), 1),
+    };
+    let &words = OPERATOR_WORDS.get(operator.as_str())?;
+
+    // Operators need operands on both sides, with whitespace either on both sides or on none,
+    // so that e.g. "*" in "the *big* dog" is not read as "times"
+    let before = index.checked_sub(1).map(|i| &tokens[i]);
+    let after = tokens.get(index + length);
+    let spaced = |tc: Option<&TokenContext>| tc.is_some_and(|tc| tc.token == Token::Whitespace);
+    let operand_before = tokens[..index]
+        .iter()
+        .rev()
+        .find(|tc| tc.token != Token::Whitespace)
+        .is_some_and(|tc| is_operand(&tc.token));
+    let operand_after =
+        next_non_whitespace(tokens, index + length - 1).is_some_and(|tc| is_operand(&tc.token));
+
+    (operand_before && operand_after && spaced(before) == spaced(after)).then_some((words, length))
+}
+
+/// Get the closest token after the token at `index` that is not whitespace.
+fn next_non_whitespace<'a, 'b>(
+    tokens: &'b [TokenContext<'a>],
+    index: usize,
+) -> Option<&'b TokenContext<'a>> {
+    tokens[index + 1..]
+        .iter()
+        .find(|tc| tc.token != Token::Whitespace)
+}
+
+/// Check if the number at `index` is a code that is read in digit groups, i.e. it follows a word from
+/// [`CODE_WORDS`] (optionally with a colon, e.g. "PIN: 1234" or "Call 555-1234"), or it is a five digit ZIP
+/// code following a state abbreviation or a place name, e.g. "CA 90210-1234" or "Beverly Hills 90210".
+fn is_code(tokens: &[TokenContext], index: usize) -> bool {
+    let Some(previous_index) = tokens[..index]
+        .iter()
+        .rposition(|tc| tc.token != Token::Whitespace && tc.slice != ":")
+        .filter(|&i| tokens[i].token == Token::Word)
+    else {
+        return false;
+    };
+    let previous = tokens[previous_index].slice;
+
+    let is_state = previous.len() == 2 && previous.chars().all(|c| c.is_ascii_uppercase());
+    let is_zip_code = tokens[index].slice.split('-').next().map(str::len) == Some(5)
+        && (is_state || is_name(tokens, previous_index));
+
+    is_zip_code || CODE_WORDS.contains(previous.to_lowercase().as_str())
+}
+
+/// Check if the word at `index` looks like a name, i.e. it is capitalized, but not only because it starts
+/// the sentence, and it is not a function word, e.g. "Henry" in "King Henry VIII", but not "The" in "The IV
+/// drip" or "Over" in "Over 50000 people".
+fn is_name(tokens: &[TokenContext], index: usize) -> bool {
+    let word = tokens[index].slice;
+    let starts_sentence = tokens[..index]
+        .iter()
+        .rev()
+        .find(|tc| tc.token != Token::Whitespace)
+        .is_none_or(|tc| tc.token == Token::SentenceDelimiter);
+
+    word.starts_with(char::is_uppercase)
+        && !starts_sentence
+        && !FUNCTION_WORDS.contains(word.to_lowercase().as_str())
+}
+
+/// Get the closest word before the token at `index`, ignoring whitespace. If the closest
+/// non-whitespace token is not a word, `None` is returned.
+fn previous_word<'a>(tokens: &[TokenContext<'a>], index: usize) -> Option<&'a str> {
+    tokens[..index]
+        .iter()
+        .rev()
+        .find(|tc| tc.token != Token::Whitespace)
+        .filter(|tc| tc.token == Token::Word)
+        .map(|tc| tc.slice)
+}
+
+/// Get the closest word after the token at `index`, ignoring whitespace. If the closest
+/// non-whitespace token is not a word, `None` is returned.
+fn next_word<'a>(tokens: &[TokenContext<'a>], index: usize) -> Option<&'a str> {
+    tokens[index + 1..]
+        .iter()
+        .find(|tc| tc.token != Token::Whitespace)
+        .filter(|tc| tc.token == Token::Word)
+        .map(|tc| tc.slice)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verbalize the last occurrence of `numeral` in `text`, see `EnPhonemizer::verbalize_roman_numeral`.
+    fn roman_numeral(text: &str, numeral: &str) -> Option<String> {
+        let phonemizer = EnPhonemizer::new().unwrap();
+        let tokens = EnTokenizer::tokenize(text).unwrap();
+        let index = tokens.iter().rposition(|tc| tc.slice == numeral).unwrap();
+
+        phonemizer.verbalize_roman_numeral(&tokens, index)
+    }
+
+    /// Phonemize a text and get the words with the sources of their phonemes.
+    fn word_sources(phonemizer: &EnPhonemizer, text: &str) -> Vec<(String, TokenSource)> {
+        phonemizer
+            .phonemize_traced(text)
+            .unwrap()
+            .into_iter()
+            .filter(|traced| traced.source.is_spoken())
+            .map(|traced| (traced.graphemes, traced.source))
+            .collect()
+    }
+
+    #[test]
+    fn traced_test() {
+        let phonemizer = EnPhonemizer::new().unwrap();
+        let text = "Hello, elephanted elephantwatch costs $5 million";
+        let traced = phonemizer.phonemize_traced(text).unwrap();
+
+        let graphemes: Vec<&str> = traced.iter().map(|t| t.graphemes.as_str()).collect();
+        assert_eq!(
+            graphemes,
+            [
+                "Hello",
+                ",",
+                " ",
+                "elephanted",
+                " ",
+                "elephantwatch",
+                " ",
+                "costs",
+                " ",
+                "$5 million"
+            ]
+        );
+        for t in &traced {
+            assert_eq!(&text[t.span.clone()], t.graphemes);
+        }
+
+        assert!(matches!(
+            traced[0].source,
+            TokenSource::Word(TranscriptionSource::Dictionary {
+                dict: "us_gold",
+                ..
+            })
+        ));
+        assert_eq!(traced[1].source, TokenSource::Passthrough);
+        assert_eq!(traced[1].phonemes, ",");
+        assert_eq!(traced[2].source, TokenSource::Passthrough);
+        assert_eq!(
+            traced[3].source,
+            TokenSource::Word(TranscriptionSource::Suffix {
+                stem: String::from("elephant"),
+                suffix: "-ed",
+            })
+        );
+        assert_eq!(
+            traced[5].source,
+            TokenSource::Word(TranscriptionSource::Compound {
+                parts: vec![String::from("elephant"), String::from("watch")],
             })
+        );
+        assert_eq!(
+            traced[9].source,
+            TokenSource::Verbalized {
+                words: String::from("five million dollars"),
+            }
+        );
+    }
+
+    #[test]
+    fn traced_clitic_test() {
+        let phonemizer = EnPhonemizer::new().unwrap();
+        let clitic = |stem: &str, clitic: &str, source| {
+            TokenSource::Word(TranscriptionSource::Clitic {
+                stem: String::from(stem),
+                clitic: String::from(clitic),
+                source: Box::new(source),
+            })
+        };
+
+        let sources: Vec<TokenSource> =
+            word_sources(&phonemizer, "Hello'll elephanted's elephantwatch's")
+                .into_iter()
+                .map(|(_, source)| source)
+                .collect();
+        assert_eq!(
+            sources,
+            [
+                clitic(
+                    "Hello",
+                    "'ll",
+                    TranscriptionSource::Dictionary {
+                        dict: "us_gold",
+                        variant: None,
+                    }
+                ),
+                clitic(
+                    "elephanted",
+                    "'s",
+                    TranscriptionSource::Suffix {
+                        stem: String::from("elephant"),
+                        suffix: "-ed",
+                    }
+                ),
+                clitic(
+                    "elephantwatch",
+                    "'s",
+                    TranscriptionSource::Compound {
+                        parts: vec![String::from("elephant"), String::from("watch")],
+                    }
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn traced_variant_test() {
+        let phonemizer = EnPhonemizer::new().unwrap();
+
+        // Homographs use the DEFAULT variant
+        let (entry_dict, entry) = WORD2IPA_EN.lookup_loose_with_dict("read").unwrap();
+        let expected_variant = match entry {
+            TranscriptionEntry::Single(_) => None,
+            TranscriptionEntry::Multiple(_) => Some("DEFAULT"),
+        };
+        assert_eq!(
+            word_sources(&phonemizer, "read"),
+            [(
+                String::from("read"),
+                TokenSource::Word(TranscriptionSource::Dictionary {
+                    dict: entry_dict,
+                    variant: expected_variant,
+                })
+            )]
+        );
+    }
+
+    #[test]
+    fn aligned_test() {
+        let phonemizer = EnPhonemizer::new().unwrap();
+        // NFKC changes "ﬁ", "Ｔ" and "…", and "―" is replaced by "--"
+        let text = "A ﬁne day ― Ｔhe end…";
+        let aligned = phonemizer.phonemize_aligned(text).unwrap();
+        assert_eq!(aligned.phonemes, phonemizer.phonemize(text).unwrap());
+
+        let spans: Vec<(&str, Range<usize>, Range<usize>)> = aligned
+            .alignments
+            .iter()
+            .map(|a| (&text[a.span.clone()], a.span.clone(), a.char_span.clone()))
             .collect();
-        Ok(results.join(""))
+        assert_eq!(
+            spans,
+            [
+                ("A", 0..1, 0..1),
+                (" ", 1..2, 1..2),
+                ("ﬁne", 2..7, 2..5),
+                (" ", 7..8, 5..6),
+                ("day", 8..11, 6..9),
+                (" ", 11..12, 9..10),
+                ("―", 12..15, 10..11),
+                ("―", 12..15, 10..11),
+                (" ", 15..16, 11..12),
+                ("Ｔhe", 16..21, 12..15),
+                (" ", 21..22, 15..16),
+                ("end", 22..25, 16..19),
+                ("…", 25..28, 19..20),
+            ]
+        );
+
+        let chars: Vec<char> = text.chars().collect();
+        for a in &aligned.alignments {
+            let by_chars: String = chars[a.char_span.clone()].iter().collect();
+            assert_eq!(by_chars, &text[a.span.clone()]);
+        }
+
+        let phonemes: Vec<&str> = aligned
+            .alignments
+            .iter()
+            .map(|a| &aligned.phonemes[a.phonemes_span.clone()])
+            .collect();
+        assert_eq!(phonemes[6..9], ["-", "-", " "]);
+        assert_eq!(phonemes[12], "...");
+    }
+
+    #[test]
+    fn stream_test() {
+        let phonemizer = EnPhonemizer::new().unwrap();
+        let chunks = [
+            "Hel",
+            "lo there. It costs $3.",
+            "50 now. Ask Dr",
+            ". Smith at example.",
+            "com today. Bye",
+        ];
+        let expected: [&[&str]; 5] = [
+            &[],
+            &["Hello there."],
+            &["It costs $3.50 now."],
+            &[],
+            &["Ask Dr. Smith at example.com today."],
+        ];
+
+        let mut stream = EnStreamPhonemizer::new(&phonemizer);
+        let mut sentences = Vec::new();
+        for (chunk, expected) in chunks.into_iter().zip(expected) {
+            let completed = stream.push(chunk).unwrap();
+            let texts: Vec<&str> = completed.iter().map(|s| s.text.as_str()).collect();
+            assert_eq!(texts, expected);
+            sentences.extend(completed);
+        }
+        let remaining = stream.finish().unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].text, "Bye");
+        sentences.extend(remaining);
+
+        // Spans refer to all text pushed, and the result is the same as without streaming
+        let text = chunks.concat();
+        for sentence in &sentences {
+            assert_eq!(&text[sentence.span.clone()], sentence.text);
+        }
+        assert_eq!(sentences, phonemizer.phonemize_sentences(&text).unwrap());
+    }
+
+    #[test]
+    fn stream_split_at_dot_test() {
+        let phonemizer = EnPhonemizer::new().unwrap();
+        let texts = [
+            "Write to a.b@example.com. Thanks",
+            "See www.example.org/docs. Bye",
+            "We use Node.js daily. It works",
+        ];
+
+        // Streaming gives the same sentences as batch phonemization, wherever a chunk ends at a dot
+        for text in texts {
+            let expected = phonemizer.phonemize_sentences(text).unwrap();
+            for (position, _) in text.match_indices('.') {
+                let mut stream = EnStreamPhonemizer::new(&phonemizer);
+                let mut sentences = stream.push(&text[..=position]).unwrap();
+                sentences.extend(stream.push(&text[position + 1..]).unwrap());
+                sentences.extend(stream.finish().unwrap());
+                assert_eq!(sentences, expected, "{text:?} split after byte {position}");
+            }
+        }
+    }
+
+    #[test]
+    fn iter_test() {
+        let phonemizer = EnPhonemizer::with_options(EnPhonemizerOptions {
+            drop_decorative_symbols: true,
+            ..Default::default()
+        })
+        .unwrap();
+        let text = "Hello there. It costs $3.50 now.\n*** Ask Dr. Smith at example.com today. \
+                    See you at 9 a.m. on Monday, OK? Bye";
+
+        let mut whole = phonemizer.phonemize_iter(text);
+        whole.window_size = usize::MAX;
+        let whole: Vec<PhonemeItem> = whole.map(Result::unwrap).collect();
+
+        // Small windows only phonemize the first sentences, and give the same items
+        let mut iter = phonemizer.phonemize_iter(text);
+        iter.window_size = 16;
+        let first = iter.next().unwrap().unwrap();
+        assert_eq!(
+            first,
+            PhonemeItem::Word(phonemizer.phonemize("Hello").unwrap())
+        );
+        assert!(iter.remaining.starts_with(" It costs $3.50 now."));
+        let items: Vec<PhonemeItem> = std::iter::once(first)
+            .chain(iter.map(Result::unwrap))
+            .collect();
+        assert_eq!(items, whole);
+
+        let boundaries = items
+            .iter()
+            .filter(|item| **item == PhonemeItem::SentenceBoundary)
+            .count();
+        assert_eq!(
+            boundaries,
+            phonemizer.phonemize_sentences(text).unwrap().len()
+        );
+        assert!(!items.contains(&PhonemeItem::Punctuation(String::new())));
+    }
+
+    #[test]
+    fn char_offsets_test() {
+        let mut char_offsets = CharOffsets::new("aäb€c");
+        assert_eq!(char_offsets.convert(0), 0);
+        assert_eq!(char_offsets.convert(3), 2);
+        assert_eq!(char_offsets.convert(7), 4);
+        // Offsets before the previous one are counted from the start again
+        assert_eq!(char_offsets.convert(1), 1);
+        assert_eq!(char_offsets.convert(8), 5);
+    }
+
+    #[test]
+    fn acronym_in_all_caps_text_test() {
+        let phonemizer = EnPhonemizer::new().unwrap();
+        let is_spelled = |text: &str| -> Vec<bool> {
+            word_sources(&phonemizer, text)
+                .into_iter()
+                .map(|(_, source)| source == TokenSource::Spelled)
+                .collect()
+        };
+
+        assert_eq!(is_spelled("THE END"), [false, false]);
+        assert_eq!(is_spelled("I LOVE YOU"), [false, false, false]);
+        assert_eq!(is_spelled("THE XQZ"), [false, true]);
+        assert_eq!(
+            is_spelled("the XQZ and the QXV"),
+            [false, true, false, false, true]
+        );
+        // Without all caps neighbors, short acronyms are spelled
+        assert_eq!(is_spelled("You, YOU!"), [false, true]);
+
+        let phonemizer = EnPhonemizer::with_options(EnPhonemizerOptions {
+            acronym_policy: AcronymPolicy::Spell,
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(
+            word_sources(&phonemizer, "THE END")
+                .into_iter()
+                .map(|(_, source)| source)
+                .collect::<Vec<_>>(),
+            [TokenSource::Spelled, TokenSource::Spelled]
+        );
+    }
+
+    #[test]
+    fn compound_name_test() {
+        let phonemizer = EnPhonemizer::new().unwrap();
+
+        // Capitalized words are more likely names, so they are phonemized with the FST
+        assert_eq!(
+            phonemizer.transcribe_word("sandwatch").unwrap().source,
+            TranscriptionSource::Compound {
+                parts: vec![String::from("sand"), String::from("watch")],
+            }
+        );
+        assert_eq!(
+            phonemizer.transcribe_word("Sandwatch").unwrap().source,
+            TranscriptionSource::Fst
+        );
+    }
+
+    #[test]
+    fn compound_with_number_test() {
+        let phonemizer = EnPhonemizer::new().unwrap();
+        let traced = phonemizer.phonemize_traced("COVID-19").unwrap();
+
+        assert_eq!(traced.len(), 1);
+        assert_eq!(
+            traced[0].source,
+            TokenSource::Split {
+                parts: vec![String::from("COVID"), String::from("19")],
+            }
+        );
+        let expected = join_with_head_stress(&[
+            phonemizer.acronym2phonemes("COVID").unwrap().0,
+            phonemizer.words2phonemes("nineteen").unwrap(),
+        ]);
+        assert_eq!(traced[0].phonemes, expected);
+        assert!(!traced[0].phonemes.contains('-'));
+    }
+
+    #[test]
+    fn code_test() {
+        let phonemizer = EnPhonemizer::new().unwrap();
+        // Verbalize the last number in the text
+        let verbalize = |text: &str| -> String {
+            let traced = phonemizer.phonemize_traced(text).unwrap();
+            match &traced.last().unwrap().source {
+                TokenSource::Verbalized { words } => words.clone(),
+                source => panic!("{text:?} was not verbalized: {source:?}"),
+            }
+        };
+
+        assert_eq!(
+            verbalize("Call 555-1234"),
+            "five five five, one two three four"
+        );
+        assert_eq!(verbalize("ZIP 02139"), "oh two one three nine");
+        assert_eq!(verbalize("Beverly Hills 90210"), "nine oh two one oh");
+        assert_eq!(verbalize("Los Angeles, CA 90210"), "nine oh two one oh");
+        assert_eq!(verbalize("Over 50000"), "fifty thousand");
+        assert_eq!(
+            verbalize("We sold 500-1500"),
+            "five hundred to one thousand five hundred"
+        );
+    }
+
+    #[test]
+    fn year_test() {
+        let phonemizer = EnPhonemizer::new().unwrap();
+        // Verbalize the last digit sequence in the text
+        let verbalize = |text: &str| -> String {
+            let tokens = EnTokenizer::tokenize(text).unwrap();
+            let index = tokens
+                .iter()
+                .rposition(|tc| tc.token == Token::DigitSequence)
+                .unwrap();
+            phonemizer.verbalize_digit_sequence(&tokens, index).0
+        };
+
+        assert_eq!(verbalize("in 1995"), "nineteen ninety-five");
+        assert_eq!(verbalize("In May 2024"), "twenty twenty-four");
+        assert_eq!(verbalize("March 2020 was"), "twenty twenty");
+        assert_eq!(verbalize("since Dec. 2019"), "twenty nineteen");
+        assert_eq!(verbalize("2010 AD"), "twenty ten");
+        assert_eq!(verbalize("we may 2024"), "two thousand twenty-four");
+        assert_eq!(
+            verbalize("1995 cars"),
+            "one thousand nine hundred ninety-five"
+        );
+    }
+
+    #[test]
+    fn roman_numeral_test() {
+        assert_eq!(
+            roman_numeral("It was Henry VIII", "VIII").unwrap(),
+            "the eighth"
+        );
+        assert_eq!(roman_numeral("World War II", "II").unwrap(), "two");
+        assert_eq!(roman_numeral("World War I", "I").unwrap(), "one");
+        assert_eq!(roman_numeral("Chapter I", "I").unwrap(), "one");
+        assert_eq!(
+            roman_numeral("Super Bowl LVII", "LVII").unwrap(),
+            "fifty-seven"
+        );
+        assert_eq!(
+            roman_numeral("Chapter XXIV", "XXIV").unwrap(),
+            "twenty-four"
+        );
+        assert_eq!(roman_numeral("in this part I explain", "I"), None);
+        assert_eq!(roman_numeral("the book I read", "I"), None);
+        assert_eq!(roman_numeral("the book V", "V"), None);
+        assert_eq!(roman_numeral("USB Type C", "C"), None);
+        assert_eq!(roman_numeral("Medicare Part D", "D"), None);
+        assert_eq!(roman_numeral("The IV drip", "IV"), None);
+        assert_eq!(roman_numeral("Hang it. Give IV fluids", "IV"), None);
+        assert_eq!(roman_numeral("We saw In IV", "IV"), None);
     }
 }
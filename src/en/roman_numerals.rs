@@ -0,0 +1,116 @@
+//! Verbalization of Roman numerals in names and titles, such as "Henry VIII" or "World War II".
+
+use super::numbers;
+
+/// Values of the Roman numeral symbols, including the subtractive pairs, from largest to smallest.
+const SYMBOLS: [(&str, u64); 13] = [
+    ("M", 1000),
+    ("CM", 900),
+    ("D", 500),
+    ("CD", 400),
+    ("C", 100),
+    ("XC", 90),
+    ("L", 50),
+    ("XL", 40),
+    ("X", 10),
+    ("IX", 9),
+    ("V", 5),
+    ("IV", 4),
+    ("I", 1),
+];
+
+/// Largest number that is read as a regnal number after a name, e.g. "Louis XIV". Larger numerals are more
+/// likely to be words or initials, e.g. "MIX" or "CV".
+const MAX_REGNAL_NUMBER: u64 = 39;
+
+/// Get the value of an uppercase Roman numeral, e.g. "XIV" is 14. Only numerals in canonical form are
+/// accepted, e.g. "IIII" or "VX" are not numerals.
+pub fn value(numeral: &str) -> Option<u64> {
+    let mut remaining = numeral;
+    let mut value = 0;
+
+    for (symbol, symbol_value) in SYMBOLS {
+        while let Some(rest) = remaining.strip_prefix(symbol) {
+            remaining = rest;
+            value += symbol_value;
+        }
+    }
+
+    // Non-canonical numerals, such as "IIII", are not converted back to the same numeral
+    (remaining.is_empty() && (1..4000).contains(&value) && to_numeral(value) == numeral)
+        .then_some(value)
+}
+
+/// Verbalize a Roman numeral after a name as a regnal number, e.g. "VIII" becomes "the eighth". "I" and "X"
+/// are excluded, since they are usually the pronoun or a letter, e.g. "Malcolm X".
+pub fn regnal(numeral: &str) -> Option<String> {
+    if matches!(numeral, "I" | "X") {
+        return None;
+    }
+
+    value(numeral)
+        .filter(|&value| value <= MAX_REGNAL_NUMBER)
+        .map(|value| {
+            format!(
+                "the {}",
+                numbers::ordinal_from_cardinal(&numbers::cardinal_from_u64(value))
+            )
+        })
+}
+
+/// Verbalize a Roman numeral after a title word as a cardinal number, e.g. "Chapter IV" is read as
+/// "Chapter four", and "Super Bowl LVII" as "Super Bowl fifty-seven". Single letters other than "I", "V" and
+/// "X" are excluded, since they are usually letters, e.g. "Type C" or "Part D".
+pub fn cardinal(numeral: &str) -> Option<String> {
+    if matches!(numeral, "L" | "C" | "D" | "M") {
+        return None;
+    }
+
+    value(numeral).map(numbers::cardinal_from_u64)
+}
+
+fn to_numeral(mut value: u64) -> String {
+    let mut numeral = String::new();
+    for (symbol, symbol_value) in SYMBOLS {
+        while value >= symbol_value {
+            numeral.push_str(symbol);
+            value -= symbol_value;
+        }
+    }
+    numeral
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn value_test() {
+        assert_eq!(value("VIII"), Some(8));
+        assert_eq!(value("XIV"), Some(14));
+        assert_eq!(value("LVII"), Some(57));
+        assert_eq!(value("MCMXCIV"), Some(1994));
+        assert_eq!(value("IIII"), None);
+        assert_eq!(value("VX"), None);
+        assert_eq!(value("viii"), None);
+        assert_eq!(value("HI"), None);
+    }
+
+    #[test]
+    fn regnal_test() {
+        assert_eq!(regnal("VIII").unwrap(), "the eighth");
+        assert_eq!(regnal("II").unwrap(), "the second");
+        assert_eq!(regnal("I"), None);
+        assert_eq!(regnal("MIX"), None);
+    }
+
+    #[test]
+    fn cardinal_test() {
+        assert_eq!(cardinal("II").unwrap(), "two");
+        assert_eq!(cardinal("XXIV").unwrap(), "twenty-four");
+        assert_eq!(cardinal("I").unwrap(), "one");
+        assert_eq!(cardinal("LVII").unwrap(), "fifty-seven");
+        assert_eq!(cardinal("C"), None);
+        assert_eq!(cardinal("D"), None);
+    }
+}
@@ -0,0 +1,230 @@
+//! Decomposition of inflected words, such as "unfriended" or "happiest", into a stem and an inflectional
+//! suffix. This is used for words that are missing from the dicts, while their stem is found.
+
+use super::clitics;
+
+/// Inflectional suffixes that can be removed from a word.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Suffix {
+    S,
+    Ed,
+    Ing,
+    Er,
+    Est,
+    Ly,
+}
+
+impl Suffix {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Suffix::S => "-s",
+            Suffix::Ed => "-ed",
+            Suffix::Ing => "-ing",
+            Suffix::Er => "-er",
+            Suffix::Est => "-est",
+            Suffix::Ly => "-ly",
+        }
+    }
+}
+
+/// Stems shorter than this are not considered, e.g. "king" is not "k" + "-ing".
+const MIN_STEM_LENGTH: usize = 3;
+
+/// Letters that are vowels for the spelling rules.
+const VOWELS: &str = "aeiouy";
+
+/// Endings after which the plural "-s" is written "-es", e.g. "boxes" or "wishes".
+const SIBILANTS: [&str; 5] = ["s", "x", "z", "ch", "sh"];
+
+/// Get all possible stems of a word with their suffix, in order of preference. Spelling rules are reversed,
+/// e.g. "stopped" can be "stop" (doubling), "carried" can be "carry" (y to i), and "liked" can be "like"
+/// (e-deletion). Most candidates are not actual words, they need to be checked against the dicts.
+pub fn candidates(word: &str) -> Vec<(String, Suffix)> {
+    let lowercase = word.to_lowercase();
+    let mut candidates = Vec::new();
+
+    for (ending, suffix) in [
+        ("s", Suffix::S),
+        ("ed", Suffix::Ed),
+        ("ing", Suffix::Ing),
+        ("er", Suffix::Er),
+        ("est", Suffix::Est),
+        ("ly", Suffix::Ly),
+    ] {
+        let Some(stem) = lowercase.strip_suffix(ending) else {
+            continue;
+        };
+
+        match suffix {
+            Suffix::S => {
+                // "cities" -> "city"
+                if let Some(stem) = stem.strip_suffix("ie") {
+                    candidates.push((format!("{}y", stem), suffix));
+                }
+                // "hopes" -> "hope", but not "glass" -> "glas"
+                if !stem.ends_with('s') {
+                    candidates.push((String::from(stem), suffix));
+                }
+                // "boxes" -> "box", "wishes" -> "wish"
+                if let Some(stem) = stem.strip_suffix('e')
+                    && SIBILANTS.iter().any(|sibilant| stem.ends_with(sibilant))
+                {
+                    candidates.push((String::from(stem), suffix));
+                }
+            }
+            Suffix::Ly => {
+                // "happily" -> "happy", "simply" -> "simple"
+                if let Some(stem) = stem.strip_suffix('i') {
+                    candidates.push((format!("{}y", stem), suffix));
+                }
+                candidates.push((format!("{}le", stem), suffix));
+                candidates.push((String::from(stem), suffix));
+            }
+            _ => {
+                // "carried" -> "carry", "happiest" -> "happy"
+                if suffix != Suffix::Ing
+                    && let Some(stem) = stem.strip_suffix('i')
+                {
+                    candidates.push((format!("{}y", stem), suffix));
+                }
+                // "stopped" -> "stop"
+                let mut chars = stem.chars().rev();
+                if let (Some(last), Some(second_last)) = (chars.next(), chars.next())
+                    && last == second_last
+                    && !VOWELS.contains(last)
+                {
+                    candidates.push((String::from(&stem[..stem.len() - last.len_utf8()]), suffix));
+                }
+                // "unfriended" -> "unfriend", "liked" -> "like". Stems ending in a single vowel and a
+                // consonant are more likely to have lost an "e", e.g. "hoping" is "hope", while "hopping"
+                // is "hop".
+                let mut chars = stem.chars().rev();
+                let ends_in_single_vowel_and_consonant =
+                    match (chars.next(), chars.next(), chars.next()) {
+                        (Some(last), Some(vowel), before) => {
+                            !VOWELS.contains(last)
+                                && VOWELS.contains(vowel)
+                                && before.is_none_or(|c| !VOWELS.contains(c))
+                        }
+                        _ => false,
+                    };
+                if ends_in_single_vowel_and_consonant {
+                    candidates.push((format!("{}e", stem), suffix));
+                    candidates.push((String::from(stem), suffix));
+                } else {
+                    candidates.push((String::from(stem), suffix));
+                    candidates.push((format!("{}e", stem), suffix));
+                }
+            }
+        }
+    }
+
+    candidates.retain(|(stem, _)| stem.chars().count() >= MIN_STEM_LENGTH);
+    candidates
+}
+
+/// Attach the phonemes of a suffix to the phonemes of its stem. The stress of the stem is kept. The form of
+/// "-s" and "-ed" depends on the last phoneme of the stem, e.g. "-ed" is "ɪd" in "wanted", "t" in "liked"
+/// and "d" in "unfriended".
+pub fn attach(stem_phonemes: &str, suffix: Suffix) -> String {
+    let last = clitics::last_phoneme(stem_phonemes);
+    let suffix_phonemes = match suffix {
+        Suffix::S => clitics::s_allomorph(stem_phonemes),
+        Suffix::Ed if last.is_some_and(|c| "td".contains(c)) => "ɪd",
+        Suffix::Ed if last.is_some_and(|c| "pkfθsʃʧ".contains(c)) => "t",
+        Suffix::Ed => "d",
+        Suffix::Ing => "ɪŋ",
+        Suffix::Er => "əɹ",
+        Suffix::Est => "əst",
+        Suffix::Ly => "li",
+    };
+
+    // The syllabic "əl" of stems like "simple" is lost, e.g. "simply" is "sˈɪmpli"
+    let stem_phonemes = match suffix {
+        Suffix::Ly => stem_phonemes
+            .strip_suffix("əl")
+            .filter(|stem| !stem.is_empty())
+            .unwrap_or(stem_phonemes),
+        _ => stem_phonemes,
+    };
+
+    format!("{}{}", stem_phonemes, suffix_phonemes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Stems known to the tests, instead of the dicts.
+    const STEMS: &[&str] = &[
+        "unfriend", "like", "stop", "carry", "make", "run", "city", "box", "happy", "big",
+        "simple", "hop", "hope", "plan", "plane", "cut", "cute", "not", "note", "horse", "bus",
+        "wish", "open", "want",
+    ];
+
+    fn contains(word: &str, stem: &str, suffix: Suffix) -> bool {
+        candidates(word).contains(&(String::from(stem), suffix))
+    }
+
+    /// Get the first candidate stem of the word that is in [`STEMS`].
+    fn first_stem(word: &str) -> Option<String> {
+        candidates(word)
+            .into_iter()
+            .map(|(stem, _)| stem)
+            .find(|stem| STEMS.contains(&stem.as_str()))
+    }
+
+    #[test]
+    fn candidates_test() {
+        assert!(contains("unfriended", "unfriend", Suffix::Ed));
+        assert!(contains("liked", "like", Suffix::Ed));
+        assert!(contains("stopped", "stop", Suffix::Ed));
+        assert!(contains("carried", "carry", Suffix::Ed));
+        assert!(contains("making", "make", Suffix::Ing));
+        assert!(contains("running", "run", Suffix::Ing));
+        assert!(contains("cities", "city", Suffix::S));
+        assert!(contains("boxes", "box", Suffix::S));
+        assert!(contains("happiest", "happy", Suffix::Est));
+        assert!(contains("bigger", "big", Suffix::Er));
+        assert!(contains("happily", "happy", Suffix::Ly));
+        assert!(contains("simply", "simple", Suffix::Ly));
+        assert!(candidates("king").is_empty());
+    }
+
+    #[test]
+    fn candidates_order_test() {
+        let expected = [
+            ("hoping", "hope"),
+            ("hopping", "hop"),
+            ("planed", "plane"),
+            ("planned", "plan"),
+            ("cuter", "cute"),
+            ("cutter", "cut"),
+            ("hopes", "hope"),
+            ("hops", "hop"),
+            ("notes", "note"),
+            ("nots", "not"),
+            ("horses", "horse"),
+            ("boxes", "box"),
+            ("buses", "bus"),
+            ("wishes", "wish"),
+            ("opened", "open"),
+            ("wanted", "want"),
+            ("liked", "like"),
+            ("unfriended", "unfriend"),
+        ];
+
+        for (word, stem) in expected {
+            assert_eq!(first_stem(word).as_deref(), Some(stem), "{}", word);
+        }
+    }
+
+    #[test]
+    fn attach_test() {
+        assert_eq!(attach("wˈɑnt", Suffix::Ed), "wˈɑntɪd");
+        assert_eq!(attach("lˈIk", Suffix::Ed), "lˈIkt");
+        assert_eq!(attach("ʌnfɹˈɛnd", Suffix::Ed), "ʌnfɹˈɛndɪd");
+        assert_eq!(attach("bˈɑks", Suffix::S), "bˈɑksɪz");
+        assert_eq!(attach("sˈɪmpəl", Suffix::Ly), "sˈɪmpli");
+    }
+}
@@ -0,0 +1,135 @@
+//! Verbalization of clock times and durations, such as "10:30", "7:05 pm" or "3:45:10".
+
+use super::numbers;
+use super::phonemizer::TimeReading;
+
+/// Verbalize a time as matched by `Token::Time`. Clock times are read like "ten thirty",
+/// "seven oh five P M" or "fourteen hundred". With [`TimeReading::Duration`], times without
+/// "am" or "pm" are read as durations instead, e.g. "three hours forty-five minutes ten seconds".
+pub fn verbalize(time: &str, reading: TimeReading) -> String {
+    let (clock, meridiem) = match time.find(|c: char| c.is_ascii_alphabetic()) {
+        Some(i) => (time[..i].trim_end(), meridiem_words(&time[i..])),
+        None => (time, None),
+    };
+
+    let parts: Vec<u64> = clock
+        .split(':')
+        .map(|part| part.parse().unwrap_or_default())
+        .collect();
+    let (hours, minutes, seconds) = match parts.as_slice() {
+        [hours] => (*hours, 0, None),
+        [hours, minutes] => (*hours, *minutes, None),
+        [hours, minutes, seconds, ..] => (*hours, *minutes, Some(*seconds)),
+        [] => (0, 0, None),
+    };
+
+    match (reading, meridiem) {
+        (TimeReading::Duration, None) => verbalize_duration(hours, minutes, seconds),
+        _ => verbalize_clock(hours, minutes, seconds, meridiem),
+    }
+}
+
+fn verbalize_clock(
+    hours: u64,
+    minutes: u64,
+    seconds: Option<u64>,
+    meridiem: Option<&str>,
+) -> String {
+    let mut words = numbers::cardinal_from_u64(hours);
+    match minutes {
+        // 24 hour times are read with "hundred", e.g. "fourteen hundred"
+        0 if meridiem.is_none() && (hours == 0 || hours > 12) => words.push_str(" hundred"),
+        0 if meridiem.is_none() => words.push_str(" o'clock"),
+        0 => {}
+        1..10 => {
+            words.push_str(" oh ");
+            words.push_str(&numbers::cardinal_from_u64(minutes));
+        }
+        _ => {
+            words.push(' ');
+            words.push_str(&numbers::cardinal_from_u64(minutes));
+        }
+    }
+
+    if let Some(meridiem) = meridiem {
+        words.push(' ');
+        words.push_str(meridiem);
+    }
+    if let Some(seconds) = seconds.filter(|&seconds| seconds > 0) {
+        words.push_str(" and ");
+        words.push_str(&count(seconds, "second", "seconds"));
+    }
+
+    words
+}
+
+fn verbalize_duration(hours: u64, minutes: u64, seconds: Option<u64>) -> String {
+    let components = [
+        (hours, "hour", "hours"),
+        (minutes, "minute", "minutes"),
+        (seconds.unwrap_or_default(), "second", "seconds"),
+    ];
+
+    let words: Vec<String> = components
+        .iter()
+        .filter(|(value, _, _)| *value > 0)
+        .map(|&(value, singular, plural)| count(value, singular, plural))
+        .collect();
+
+    if words.is_empty() {
+        count(0, "second", "seconds")
+    } else {
+        words.join(" ")
+    }
+}
+
+/// Get the words for "am" or "pm", in any spelling ("AM", "a.m.", ...). The letters are
+/// uppercase, so that they are looked up as letters and not as words.
+fn meridiem_words(meridiem: &str) -> Option<&'static str> {
+    match meridiem.chars().next()?.to_ascii_lowercase() {
+        'a' => Some("A M"),
+        'p' => Some("P M"),
+        _ => None,
+    }
+}
+
+fn count(value: u64, singular: &str, plural: &str) -> String {
+    let name = if value == 1 { singular } else { plural };
+    format!("{} {}", numbers::cardinal_from_u64(value), name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clock_test() {
+        assert_eq!(verbalize("10:30", TimeReading::Clock), "ten thirty");
+        assert_eq!(
+            verbalize("7:05 pm", TimeReading::Clock),
+            "seven oh five P M"
+        );
+        assert_eq!(verbalize("7p.m.", TimeReading::Clock), "seven P M");
+        assert_eq!(verbalize("14:00", TimeReading::Clock), "fourteen hundred");
+        assert_eq!(verbalize("9:00", TimeReading::Clock), "nine o'clock");
+        assert_eq!(
+            verbalize("3:45:10", TimeReading::Clock),
+            "three forty-five and ten seconds"
+        );
+    }
+
+    #[test]
+    fn duration_test() {
+        assert_eq!(
+            verbalize("3:45:10", TimeReading::Duration),
+            "three hours forty-five minutes ten seconds"
+        );
+        assert_eq!(verbalize("1:00", TimeReading::Duration), "one hour");
+        assert_eq!(verbalize("0:01", TimeReading::Duration), "one minute");
+        // Times with "am" or "pm" are always clock times
+        assert_eq!(
+            verbalize("7:05 AM", TimeReading::Duration),
+            "seven oh five A M"
+        );
+    }
+}
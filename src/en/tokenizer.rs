@@ -1,8 +1,13 @@
-use std::ops::Range;
+use std::ops::{Range, RangeInclusive};
 
 use anyhow::{anyhow, Result};
 use logos::Logos;
 
+use super::constants::{
+    CURRENCIES, MAGNITUDE_SUFFIXES, MONTHS, MONTH_ABBREVIATIONS, TOP_LEVEL_DOMAINS, UNITS,
+};
+use super::{abbreviations, dates};
+
 #[derive(Logos, Debug, PartialEq, Clone)]
 pub enum Token {
     // WORD TOKEN
@@ -18,44 +23,106 @@ pub enum Token {
     #[regex(r"[[\w]&&[^\d]]+(?:'[[\w]&&[^\d]]+)*")]
     Word,
 
-    // TODO: Add other abbreviations
-    #[regex(r"(?:(i:dr)|(i:ms)|(i:mr))\.")]
-    AbbreviatedWord,
-
     // NUMBER TOKENS
+    /// Represents a sequence of digits. Numbers with thousands separators, such as "1,000", are merged into a
+    /// single digit sequence after lexing (or a decimal number, e.g. "1,000.50").
     #[regex(r"\d+")]
     DigitSequence,
 
-    // TODO: Date.
-    // Multiple versions need to be considered: 01.02.2024, 1.2.2024, 01.02.24, ...
-    // Date
+    /// Represents an ordinal number written with digits and an english ordinal suffix, such as "1st", "2nd" or "23rd".
+    #[regex(r"\d+(?i:st|nd|rd|th)")]
+    Ordinal,
+
     /// Represents a decimal number with digits before and after the decimal point.
     #[regex(r"\d+\.\d+")]
     DecimalNumber,
 
     // SPECIAL TOKENS
     /// All characters considered to be sentence delimiters. Note that dot can be ambiguous,
-    /// such as in links for example (www.google.com). This situation needs to be fixed,
-    /// but is currently not simple, since Logos does not implement lookahead.
+    /// such as in links for example (www.google.com). Since Logos does not implement lookahead,
+    /// links and email addresses are merged into `Url` and `Email` tokens after lexing.
     #[regex(r"(?:[\.!?])+")]
     SentenceDelimiter,
 
     // TODO:
     // Dot
-    #[regex(r##"[\$\%\&\=\*\+\;<>|\^"'~:\/\\#,()\[\]\{\}-]"##)] // correct? should be ".,!?()[]{}-
+    #[regex(r##"[\$€£¥°\%\&\=\*\+\;<>|\^"'~:\/\\#@,()\[\]\{\}-]"##)] // correct? should be ".,!?()[]{}-
     Special,
 
-    // Currencies? So much more probably..
+    // So much more probably..
+
+    // COMPOSITE TOKENS
+    // These are not produced by the lexer, but by merging sequences of lexed tokens, see `EnTokenizer::tokenize`.
+    // Matching them with regexes would need backtracking, which Logos does not handle reliably: With a pattern
+    // for thousands separators, "2024, " is lexed as a single digit sequence including the comma.
+    /// Represents words joined by hyphens, such as "well-known" or "state-of-the-art". Parts after the first
+    /// one can also be digits, such as in "COVID-19".
+    HyphenatedWord,
+
+    /// Represents an abbreviation including its trailing dot, such as "Dr.", "e.g." or "U.S.". Only abbreviations
+    /// known to `abbreviations` or registered at runtime are considered, otherwise the dot is a sentence delimiter.
+    /// Some abbreviations are only matched in front of a number, such as "No." in "No. 5".
+    AbbreviatedWord,
+
+    /// Represents a URL, such as "https://docs.rs/phonemoro", "www.google.com" or "docs.rs". URLs without a scheme
+    /// or "www" are only matched if they end in a top level domain from `constants::TOP_LEVEL_DOMAINS`.
+    Url,
+
+    /// Represents an email address, such as "a.b@example.com".
+    Email,
+
+    /// Represents an amount of money, with the currency symbol either before or after the amount, such as
+    /// "$5.99", "€1.2M", "£1,000" or "20€". Thousands separators and magnitude suffixes
+    /// (k, m, b, bn, tn) are part of the token.
+    ///
+    /// _Simplified pattern_:
+    ///
+    /// `(symbol amount magnitude?) | (amount symbol)`
+    Currency,
+
+    /// Represents a date, either numeric or written. Numeric dates use slashes, dots or dashes as separators
+    /// (e.g. "01.02.2024", "1/2/24", "2024-02-01"), the order of day and month is resolved during
+    /// phonemization. Written dates have a month name and a day, and optionally a year (e.g. "March 3rd, 2024",
+    /// "Dec. 24", "3rd of March 2024").
+    Date,
+
+    /// Represents a phone number, such as "(555) 123-4567", "+1 800 555 0199" or "1-800-555-0199". Without a
+    /// country code or an area code in parentheses, the groups have to be separated by hyphens. Local numbers like
+    /// "555-1234" can't be told apart from ranges like "500-1500", so they are number ranges, which are read digit
+    /// by digit after words like "phone" during phonemization.
+    PhoneNumber,
+
+    /// Represents a clock time or a duration, with optional seconds and "am" / "pm", such as "10:30",
+    /// "7:05 pm", "14:00", "3:45:10" or "7a.m.". A number directly followed by "am" or "pm" is also a time.
+    Time,
+
+    /// Represents a percentage, such as "45%" or "2.5%".
+    Percentage,
+
+    /// Represents a fraction, such as "3/4" or "1/3".
+    Fraction,
+
+    /// Represents a range of numbers, such as "10-20" or "1990-2000".
+    NumberRange,
+
+    /// Represents a number directly followed by "x", such as "2x" or "1.5x".
+    Multiplier,
+
+    /// Represents a number followed by a unit of measurement, with or without whitespace in between, such as
+    /// "5 km", "12kg", "30°C" or "60 mph". Only units from `constants::UNITS` are considered.
+    Measurement,
 
     // EVERYTHING ELSE
     #[regex(r"\s+")]
     Whitespace,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct TokenContext<'a> {
     pub token: Token,
     pub slice: &'a str,
+    /// Byte range of the token in the tokenized text. The phonemizer tokenizes the normalized text, see
+    /// `EnPhonemizer::phonemize_aligned` for spans in the original input.
     pub span: Range<usize>,
 }
 
@@ -66,9 +133,95 @@ impl<'a> From<&TokenContext<'a>> for (Token, &'a str) {
     }
 }
 
+/// Checks if the tokens at the start of the slice form a composite token. If they do, the composite
+/// token and the number of tokens it is made of are returned.
+type CompositeMatcher = fn(&[TokenContext]) -> Option<(Token, usize)>;
+
+/// All matchers for composite tokens, in order of precedence.
+static COMPOSITE_MATCHERS: &[CompositeMatcher] = &[
+    match_email,
+    match_url,
+    match_currency,
+    match_date,
+    match_phone_number,
+    match_time,
+    match_number_expression,
+    match_measurement,
+    match_grouped_number,
+    match_hyphenated_word,
+];
+
 pub struct EnTokenizer;
 impl EnTokenizer {
+    /// Tokenize a text with only the built-in abbreviations. The phonemizer also passes the abbreviations
+    /// registered at runtime, see [`EnTokenizer::tokenize_with_abbreviations`].
+    #[cfg(test)]
     pub fn tokenize<'a>(text: &'a str) -> Result<Vec<TokenContext<'a>>> {
+        Self::tokenize_with_abbreviations(text, &|_| false)
+    }
+
+    /// Tokenize a text. Words for which `is_custom_abbreviation` returns true are treated as abbreviations
+    /// if followed by a dot, in addition to the built-in ones. The closure receives the abbreviation without
+    /// the trailing dot, e.g. "approx" or "e.g".
+    pub fn tokenize_with_abbreviations<'a>(
+        text: &'a str,
+        is_custom_abbreviation: &dyn Fn(&str) -> bool,
+    ) -> Result<Vec<TokenContext<'a>>> {
+        let tokens = Self::lex(text)?;
+        Ok(Self::merge_composites(
+            text,
+            &tokens,
+            is_custom_abbreviation,
+        ))
+    }
+
+    /// Split tokens into sentences. A sentence ends after a sentence delimiter (including closing quotes or
+    /// brackets directly after it) that is followed by whitespace or the end of the text, or at an empty line. Abbreviations, decimal numbers and URLs are separate
+    /// tokens, so the dots in them do not end sentences, except for abbreviations like "etc." that are followed
+    /// by a capitalized word. Returns the range of tokens of each sentence, without leading and trailing
+    /// whitespace.
+    pub fn split_sentences(tokens: &[TokenContext]) -> Vec<Range<usize>> {
+        let mut sentences = Vec::new();
+        let mut start = 0;
+
+        let mut index = 0;
+        while index < tokens.len() {
+            let tc = &tokens[index];
+            index += 1;
+
+            let end = match tc.token {
+                Token::SentenceDelimiter => {
+                    let mut end = index;
+                    while tokens
+                        .get(end)
+                        .is_some_and(|tc| matches!(tc.slice, "\"" | "'" | ")" | "]" | "}"))
+                    {
+                        end += 1;
+                    }
+                    // A word directly after the delimiter, e.g. "Node.js", continues the sentence
+                    if tokens
+                        .get(end)
+                        .is_some_and(|tc| tc.token != Token::Whitespace)
+                    {
+                        continue;
+                    }
+                    index = end;
+                    end
+                }
+                Token::AbbreviatedWord if abbreviation_ends_sentence(tokens, index - 1) => index,
+                Token::Whitespace if tc.slice.matches('\n').count() >= 2 => index - 1,
+                _ => continue,
+            };
+
+            push_sentence(&mut sentences, tokens, start..end);
+            start = index;
+        }
+        push_sentence(&mut sentences, tokens, start..tokens.len());
+
+        sentences
+    }
+
+    fn lex<'a>(text: &'a str) -> Result<Vec<TokenContext<'a>>> {
         let mut lex = Token::lexer(text);
 
         let mut tokens: Vec<TokenContext<'a>> = Vec::new();
@@ -88,6 +241,589 @@ impl EnTokenizer {
 
         Ok(tokens)
     }
+
+    /// Merge sequences of lexed tokens into composite tokens, using the [`COMPOSITE_MATCHERS`]. Abbreviations
+    /// are matched last, so that e.g. "Dec. 24" is still a date.
+    fn merge_composites<'a>(
+        text: &'a str,
+        tokens: &[TokenContext<'a>],
+        is_custom_abbreviation: &dyn Fn(&str) -> bool,
+    ) -> Vec<TokenContext<'a>> {
+        let mut merged: Vec<TokenContext<'a>> = Vec::with_capacity(tokens.len());
+
+        let mut index = 0;
+        while index < tokens.len() {
+            let composite = COMPOSITE_MATCHERS
+                .iter()
+                .find_map(|matcher| matcher(&tokens[index..]))
+                .or_else(|| match_abbreviation(&tokens[index..], is_custom_abbreviation));
+
+            match composite {
+                Some((token, length)) => {
+                    let span = tokens[index].span.start..tokens[index + length - 1].span.end;
+                    merged.push(TokenContext {
+                        token,
+                        slice: &text[span.clone()],
+                        span,
+                    });
+                    index += length;
+                }
+                None => {
+                    merged.push(tokens[index].clone());
+                    index += 1;
+                }
+            }
+        }
+
+        merged
+    }
+}
+
+/// Add a sentence with the given token range, without leading and trailing whitespace. Empty sentences
+/// are skipped.
+fn push_sentence(sentences: &mut Vec<Range<usize>>, tokens: &[TokenContext], range: Range<usize>) {
+    let is_content = |i: &usize| tokens[*i].token != Token::Whitespace;
+    if let (Some(start), Some(end)) = (
+        range.clone().find(is_content),
+        range.clone().rev().find(is_content),
+    ) {
+        sentences.push(start..end + 1);
+    }
+}
+
+/// Check if the abbreviation at `index` also ends the sentence, i.e. it can end a sentence (e.g. "etc.") and
+/// it is followed by a capitalized word.
+fn abbreviation_ends_sentence(tokens: &[TokenContext], index: usize) -> bool {
+    abbreviations::can_end_sentence(tokens[index].slice)
+        && matches!(
+            &tokens[index + 1..],
+            [whitespace, word, ..]
+                if whitespace.token == Token::Whitespace
+                    && word.token == Token::Word
+                    && word.slice.starts_with(char::is_uppercase)
+        )
+}
+
+/// Match an email address, e.g. "a.b@example.com".
+fn match_email(tokens: &[TokenContext]) -> Option<(Token, usize)> {
+    let (_, local_length) = match_host(tokens, &["-", "+"]);
+    if local_length == 0 || tokens.get(local_length)?.slice != "@" {
+        return None;
+    }
+
+    let (labels, domain_length) = match_host(&tokens[local_length + 1..], &["-"]);
+    (labels.len() >= 2).then_some((Token::Email, local_length + 1 + domain_length))
+}
+
+/// Match a URL, e.g. "https://docs.rs/phonemoro", "www.google.com" or "docs.rs".
+fn match_url(tokens: &[TokenContext]) -> Option<(Token, usize)> {
+    let scheme_length = match tokens {
+        [scheme, colon, slash, slash2, ..]
+            if scheme.token == Token::Word
+                && matches!(
+                    scheme.slice.to_lowercase().as_str(),
+                    "http" | "https" | "ftp"
+                )
+                && colon.slice == ":"
+                && slash.slice == "/"
+                && slash2.slice == "/" =>
+        {
+            4
+        }
+        _ => 0,
+    };
+
+    let (labels, host_length) = match_host(&tokens[scheme_length..], &["-"]);
+    let top_level_domain = labels.last()?.to_lowercase();
+    let valid = labels.len() >= 2
+        && (scheme_length > 0
+            || labels[0].eq_ignore_ascii_case("www")
+            || TOP_LEVEL_DOMAINS.contains(top_level_domain.as_str()));
+    if !valid {
+        return None;
+    }
+
+    // The path contains everything up to the next whitespace, except trailing punctuation
+    let mut length = scheme_length + host_length;
+    if tokens.get(length).is_some_and(|tc| tc.slice == "/") {
+        let path_length = tokens[length..]
+            .iter()
+            .take_while(|tc| tc.token != Token::Whitespace)
+            .count();
+        let trailing_punctuation = tokens[length..length + path_length]
+            .iter()
+            .rev()
+            .take_while(|tc| {
+                tc.token == Token::SentenceDelimiter
+                    || matches!(tc.slice, "," | ";" | ":" | ")" | "\"" | "'")
+            })
+            .count();
+        length += path_length - trailing_punctuation;
+    }
+
+    Some((Token::Url, length))
+}
+
+/// Match a host name, i.e. labels separated by dots, e.g. "mail.example.com". Labels are made of words and
+/// digits, joined by any of the `joiners`, e.g. "my-site". Returns the last token of each label and the number
+/// of tokens the host name is made of.
+fn match_host<'a>(tokens: &[TokenContext<'a>], joiners: &[&str]) -> (Vec<&'a str>, usize) {
+    let is_label_part = |tc: &TokenContext| matches!(tc.token, Token::Word | Token::DigitSequence);
+
+    let mut labels = Vec::new();
+    let mut length = 0;
+    loop {
+        // A label has to start and end with a word or digits
+        let mut label_length = 0;
+        while let Some(tc) = tokens.get(length + label_length) {
+            let is_joiner = joiners.contains(&tc.slice)
+                && tokens
+                    .get(length + label_length + 1)
+                    .is_some_and(is_label_part);
+            if !(is_label_part(tc) || (label_length > 0 && is_joiner)) {
+                break;
+            }
+            label_length += 1;
+        }
+        if label_length == 0 {
+            break;
+        }
+
+        length += label_length;
+        labels.push(tokens[length - 1].slice);
+
+        match &tokens[length..] {
+            [dot, next, ..] if dot.slice == "." && is_label_part(next) => length += 1,
+            _ => break,
+        }
+    }
+
+    (labels, length)
+}
+
+/// Match an amount of money, e.g. "$5.99", "€1.2M", "$1,000" or "20€".
+fn match_currency(tokens: &[TokenContext]) -> Option<(Token, usize)> {
+    let is_currency_symbol = |tc: &TokenContext| {
+        tc.token == Token::Special
+            && tc
+                .slice
+                .chars()
+                .next()
+                .is_some_and(|c| CURRENCIES.contains_key(&c))
+    };
+
+    // Symbol before the amount, optionally with a magnitude suffix directly after it
+    if tokens.first().is_some_and(is_currency_symbol) {
+        let mut length = 1 + match_amount(&tokens[1..])?;
+        if tokens
+            .get(length)
+            .is_some_and(|tc| tc.token == Token::Word && MAGNITUDE_SUFFIXES.contains_key(tc.slice))
+        {
+            length += 1;
+        }
+        return Some((Token::Currency, length));
+    }
+
+    // Symbol after the amount
+    let length = match_amount(tokens)?;
+    tokens.get(length).filter(|tc| is_currency_symbol(tc))?;
+    Some((Token::Currency, length + 1))
+}
+
+/// Match a numeric date ("01.02.2024", "1/2/24", "2024-02-01") or a written date ("March 3rd, 2024",
+/// "Dec. 24", "3rd of March 2024").
+fn match_date(tokens: &[TokenContext]) -> Option<(Token, usize)> {
+    match_numeric_date(tokens)
+        .or_else(|| match_written_date_month_first(tokens))
+        .or_else(|| match_written_date_day_first(tokens))
+        .map(|length| (Token::Date, length))
+}
+
+/// Match a numeric date. Day and month have to be in range, and dates with dots need a four digit year,
+/// since e.g. "3.10.12" is more likely a version number.
+fn match_numeric_date(tokens: &[TokenContext]) -> Option<usize> {
+    match tokens {
+        // Dates with dots are lexed as decimal number, dot, digits, e.g. "01.02" "." "2024"
+        [day_month, dot, year, ..]
+            if day_month.token == Token::DecimalNumber
+                && dot.slice == "."
+                && is_digits(year, 4..=4) =>
+        {
+            let (first, second) = day_month.slice.split_once('.')?;
+            (first.len() <= 2 && second.len() <= 2 && is_day_and_month(first, second)).then_some(3)
+        }
+        [first, separator, second, separator2, third, ..]
+            if separator.token == Token::Special
+                && matches!(separator.slice, "/" | "-")
+                && separator.slice == separator2.slice
+                && is_digits(second, 1..=2) =>
+        {
+            let day_month_year = is_digits(first, 1..=2)
+                && (is_digits(third, 2..=2) || is_digits(third, 4..=4))
+                && is_day_and_month(first.slice, second.slice);
+            let iso = separator.slice == "-"
+                && is_digits(first, 4..=4)
+                && is_digits(third, 1..=2)
+                && is_in_range(second.slice, 1..=12)
+                && is_in_range(third.slice, 1..=31);
+
+            (day_month_year || iso).then_some(5)
+        }
+        _ => None,
+    }
+}
+
+/// Check if two numbers are a day and a month of a numeric date, in either order, e.g. "31" and "12".
+fn is_day_and_month(first: &str, second: &str) -> bool {
+    (is_in_range(first, 1..=31) && is_in_range(second, 1..=12))
+        || (is_in_range(first, 1..=12) && is_in_range(second, 1..=31))
+}
+
+/// Check if the digits are a number in the given range.
+fn is_in_range(digits: &str, range: RangeInclusive<u32>) -> bool {
+    digits
+        .parse()
+        .is_ok_and(|number: u32| range.contains(&number))
+}
+
+fn match_written_date_month_first(tokens: &[TokenContext]) -> Option<usize> {
+    let month = tokens.first().filter(|tc| tc.token == Token::Word)?;
+    dates::month_number(month.slice)?;
+
+    // Abbreviated month names can be followed by a dot, e.g. "Dec. 24"
+    let mut length = 1;
+    if MONTH_ABBREVIATIONS.contains_key(month.slice)
+        && tokens.get(1).is_some_and(|tc| tc.slice == ".")
+    {
+        length += 1;
+    }
+
+    match &tokens[length..] {
+        [whitespace, day, ..] if whitespace.token == Token::Whitespace && is_day(day) => {
+            length += 2;
+        }
+        _ => return None,
+    }
+
+    Some(length + match_date_year(&tokens[length..]))
+}
+
+fn match_written_date_day_first(tokens: &[TokenContext]) -> Option<usize> {
+    let mut length = match tokens {
+        [day, whitespace, of, whitespace2, ..]
+            if is_day(day)
+                && whitespace.token == Token::Whitespace
+                && of.slice == "of"
+                && whitespace2.token == Token::Whitespace =>
+        {
+            4
+        }
+        [day, whitespace, ..] if is_day(day) && whitespace.token == Token::Whitespace => 2,
+        _ => return None,
+    };
+
+    // Only full month names, since abbreviations are too ambiguous here, e.g. "5 Decades"
+    tokens
+        .get(length)
+        .filter(|tc| tc.token == Token::Word && MONTHS.contains(&tc.slice))?;
+    length += 1;
+
+    Some(length + match_date_year(&tokens[length..]))
+}
+
+/// Match the optional year of a written date, e.g. ", 2024" or " 2024". Returns the number of tokens
+/// it is made of, 0 if there is no year.
+fn match_date_year(tokens: &[TokenContext]) -> usize {
+    match tokens {
+        [comma, whitespace, year, ..]
+            if comma.slice == ","
+                && whitespace.token == Token::Whitespace
+                && is_digits(year, 4..=4) =>
+        {
+            3
+        }
+        [whitespace, year, ..]
+            if whitespace.token == Token::Whitespace && is_digits(year, 4..=4) =>
+        {
+            2
+        }
+        _ => 0,
+    }
+}
+
+/// Match a time, e.g. "10:30", "7:05 pm", "14:00", "3:45:10" or "7pm".
+fn match_time(tokens: &[TokenContext]) -> Option<(Token, usize)> {
+    let hours = tokens.first().filter(|tc| is_digits(tc, 1..=2))?;
+
+    // Minutes and seconds
+    let mut length = 1;
+    while length < 5 {
+        match &tokens[length..] {
+            [colon, digits, ..]
+                if colon.slice == ":" && is_digits(digits, 2..=2) && digits.slice < "60" =>
+            {
+                length += 2;
+            }
+            _ => break,
+        }
+    }
+
+    let meridiem_length = match_meridiem(&tokens[length..]);
+    let max_hours = if meridiem_length > 0 { 12 } else { 24 };
+    if (length == 1 && meridiem_length == 0) || hours.slice.parse::<u32>().ok()? > max_hours {
+        return None;
+    }
+
+    Some((Token::Time, length + meridiem_length))
+}
+
+/// Match "am" or "pm" in any case, also written as "a.m." or "p.m.", optionally preceded by whitespace.
+/// Returns the number of tokens it is made of, 0 if there is no match.
+fn match_meridiem(tokens: &[TokenContext]) -> usize {
+    let offset = usize::from(
+        tokens
+            .first()
+            .is_some_and(|tc| tc.token == Token::Whitespace),
+    );
+
+    match &tokens[offset..] {
+        [letter, dot, m, dot2, ..]
+            if matches!(letter.slice, "a" | "A" | "p" | "P")
+                && dot.slice == "."
+                && matches!(m.slice, "m" | "M")
+                && dot2.slice == "." =>
+        {
+            offset + 4
+        }
+        [word, ..]
+            if word.token == Token::Word
+                && matches!(word.slice.to_lowercase().as_str(), "am" | "pm") =>
+        {
+            offset + 1
+        }
+        _ => 0,
+    }
+}
+
+/// Match a percentage ("45%"), a fraction ("3/4"), a range of numbers ("10-20") or a multiplier ("2x").
+fn match_number_expression(tokens: &[TokenContext]) -> Option<(Token, usize)> {
+    match tokens {
+        [number, percent, ..] if is_number(number) && percent.slice == "%" => {
+            Some((Token::Percentage, 2))
+        }
+        [numerator, slash, denominator, ..]
+            if numerator.token == Token::DigitSequence
+                && slash.slice == "/"
+                && denominator.token == Token::DigitSequence =>
+        {
+            Some((Token::Fraction, 3))
+        }
+        [start, dash, end, ..] if is_number(start) && dash.slice == "-" && is_number(end) => {
+            Some((Token::NumberRange, 3))
+        }
+        // Not followed by anything that would make it part of a word, e.g. in "0x1F"
+        [number, x, rest @ ..]
+            if is_number(number)
+                && matches!(x.slice, "x" | "X")
+                && !rest
+                    .first()
+                    .is_some_and(|tc| matches!(tc.token, Token::Word | Token::DigitSequence)) =>
+        {
+            Some((Token::Multiplier, 2))
+        }
+        _ => None,
+    }
+}
+
+/// Match a phone number, e.g. "(555) 123-4567", "+1 800 555 0199" or "1-800-555-0199".
+fn match_phone_number(tokens: &[TokenContext]) -> Option<(Token, usize)> {
+    let first = tokens.first()?;
+
+    let length = if first.slice == "+" {
+        let (groups, length) = match_digit_groups(&tokens[1..], true);
+        let digits: usize = groups.iter().sum();
+        let valid = groups.len() >= 3
+            && (1..=3).contains(&groups[0])
+            && groups.iter().all(|&group| group <= 4)
+            && (7..=15).contains(&digits);
+        valid.then_some(1 + length)?
+    } else {
+        // Whitespace is only a separator if there is an area code in parentheses, e.g. "(555) 123 4567"
+        let (groups, length) = match_digit_groups(tokens, first.slice == "(");
+        let valid = match groups.as_slice() {
+            [3, 3, 4] => true,
+            [1, 3, 3, 4] => first.slice != "(",
+            _ => false,
+        };
+        valid.then_some(length)?
+    };
+
+    Some((Token::PhoneNumber, length))
+}
+
+/// Match digit groups separated by hyphens (or single spaces, if `allow_whitespace` is set), e.g. "555-1234".
+/// Groups can be in parentheses, e.g. "(555)", which don't need to be followed by a separator. Returns the number
+/// of digits in each group and the number of tokens the groups are made of.
+fn match_digit_groups(tokens: &[TokenContext], allow_whitespace: bool) -> (Vec<usize>, usize) {
+    let mut groups = Vec::new();
+    let mut length = 0;
+
+    loop {
+        let (digits, group_length, parenthesized) = match &tokens[length..] {
+            [digits, ..] if digits.token == Token::DigitSequence => (digits.slice.len(), 1, false),
+            [open, digits, close, ..]
+                if open.slice == "("
+                    && digits.token == Token::DigitSequence
+                    && close.slice == ")" =>
+            {
+                (digits.slice.len(), 3, true)
+            }
+            _ => break,
+        };
+        groups.push(digits);
+        length += group_length;
+
+        let separated = tokens.get(length).is_some_and(|separator| {
+            separator.slice == "-" || (allow_whitespace && separator.slice == " ")
+        });
+        let next_group = if separated { length + 1 } else { length };
+        let next_is_group = tokens
+            .get(next_group)
+            .is_some_and(|tc| tc.token == Token::DigitSequence || tc.slice == "(");
+
+        if !next_is_group || !(separated || parenthesized) {
+            break;
+        }
+        length = next_group;
+    }
+
+    (groups, length)
+}
+
+/// Match a number followed by a unit of measurement, e.g. "5 km", "12kg", "30°C", "60 mph" or "1,500 km".
+fn match_measurement(tokens: &[TokenContext]) -> Option<(Token, usize)> {
+    let number_length = match_amount(tokens)?;
+    let rest = &tokens[number_length..];
+
+    let offset = usize::from(rest.first().is_some_and(|tc| tc.token == Token::Whitespace));
+    let rest = &rest[offset..];
+
+    // Units can be made of multiple tokens, e.g. "km/h" or "°C", so the longest one is used
+    let unit_length = (1..=3).rev().find(|&length| {
+        rest.get(..length).is_some_and(|unit| {
+            let unit: String = unit.iter().map(|tc| tc.slice).collect();
+            UNITS.contains_key(unit.as_str())
+        })
+    })?;
+
+    Some((Token::Measurement, number_length + offset + unit_length))
+}
+
+/// Match a number with thousands separators, e.g. "1,000" or "1,000.50". The result is a digit sequence
+/// or a decimal number, so it is read like any other number.
+fn match_grouped_number(tokens: &[TokenContext]) -> Option<(Token, usize)> {
+    if !is_digits(tokens.first()?, 1..=3) {
+        return None;
+    }
+
+    let length = match_amount(tokens)?;
+    let token = tokens[length - 1].token.clone();
+    (length > 1).then_some((token, length))
+}
+
+/// Match words joined by hyphens, e.g. "well-known", "state-of-the-art" or "COVID-19".
+fn match_hyphenated_word(tokens: &[TokenContext]) -> Option<(Token, usize)> {
+    if tokens.first()?.token != Token::Word {
+        return None;
+    }
+
+    let mut length = 1;
+    while let [hyphen, part, ..] = &tokens[length..] {
+        if hyphen.slice != "-" || !matches!(part.token, Token::Word | Token::DigitSequence) {
+            break;
+        }
+        length += 2;
+    }
+
+    (length > 1).then_some((Token::HyphenatedWord, length))
+}
+
+/// Match an abbreviation with its trailing dot, e.g. "Dr." or "e.g.". Abbreviations with inner dots are lexed
+/// as alternating words and dots, the longest known abbreviation is used.
+fn match_abbreviation(
+    tokens: &[TokenContext],
+    is_custom_abbreviation: &dyn Fn(&str) -> bool,
+) -> Option<(Token, usize)> {
+    let mut abbreviation = String::new();
+    let mut length = 0;
+    let mut longest = None;
+
+    while let [word, dot, ..] = &tokens[length..] {
+        if word.token != Token::Word || dot.slice != "." {
+            break;
+        }
+        abbreviation.push_str(word.slice);
+        length += 2;
+
+        let followed_by_number = match &tokens[length..] {
+            [whitespace, number, ..] => whitespace.token == Token::Whitespace && is_number(number),
+            _ => false,
+        };
+        if is_custom_abbreviation(&abbreviation)
+            || abbreviations::is_abbreviation(
+                &abbreviations::lookup_key(&abbreviation),
+                followed_by_number,
+            )
+        {
+            longest = Some(length);
+        }
+        abbreviation.push('.');
+    }
+
+    longest.map(|length| (Token::AbbreviatedWord, length))
+}
+
+/// Check if the token is a number, i.e. a digit sequence or a decimal number.
+fn is_number(tc: &TokenContext) -> bool {
+    matches!(tc.token, Token::DigitSequence | Token::DecimalNumber)
+}
+
+/// Check if the token is a day of a written date, e.g. "3" or "3rd".
+fn is_day(tc: &TokenContext) -> bool {
+    is_digits(tc, 1..=2) || (tc.token == Token::Ordinal && tc.slice.len() <= 4)
+}
+
+/// Check if the token is a digit sequence with a number of digits in the given range.
+fn is_digits(tc: &TokenContext, lengths: RangeInclusive<usize>) -> bool {
+    tc.token == Token::DigitSequence && lengths.contains(&tc.slice.len())
+}
+
+/// Match a number with optional thousands separators, e.g. "5", "1,000" or "1,000.50". Returns the
+/// number of tokens it is made of.
+fn match_amount(tokens: &[TokenContext]) -> Option<usize> {
+    match tokens.first()?.token {
+        Token::DigitSequence => {}
+        Token::DecimalNumber => return Some(1),
+        _ => return None,
+    }
+
+    let mut length = 1;
+    while let [separator, group, ..] = &tokens[length..] {
+        let group_integer_part = group.slice.split('.').next().unwrap_or_default();
+        if separator.slice != ","
+            || !matches!(group.token, Token::DigitSequence | Token::DecimalNumber)
+            || group_integer_part.len() != 3
+        {
+            break;
+        }
+
+        length += 2;
+        if group.token == Token::DecimalNumber {
+            break;
+        }
+    }
+
+    Some(length)
 }
 
 #[cfg(test)]
@@ -167,6 +903,58 @@ mod tests {
         assert_eq!(output, expected)
     }
 
+    #[test]
+    fn grouped_number_test() {
+        let text_input = "1,000 people, 12,345,678.9 and 1,2 or 2024, 5 or 1,500 km";
+
+        let output = to_tuple!(EnTokenizer::tokenize(text_input).unwrap());
+        let expected = vec![
+            (Token::DigitSequence, "1,000"),
+            (Token::Whitespace, " "),
+            (Token::Word, "people"),
+            (Token::Special, ","),
+            (Token::Whitespace, " "),
+            (Token::DecimalNumber, "12,345,678.9"),
+            (Token::Whitespace, " "),
+            (Token::Word, "and"),
+            (Token::Whitespace, " "),
+            (Token::DigitSequence, "1"),
+            (Token::Special, ","),
+            (Token::DigitSequence, "2"),
+            (Token::Whitespace, " "),
+            (Token::Word, "or"),
+            (Token::Whitespace, " "),
+            (Token::DigitSequence, "2024"),
+            (Token::Special, ","),
+            (Token::Whitespace, " "),
+            (Token::DigitSequence, "5"),
+            (Token::Whitespace, " "),
+            (Token::Word, "or"),
+            (Token::Whitespace, " "),
+            (Token::Measurement, "1,500 km"),
+        ];
+
+        assert_eq!(output, expected)
+    }
+
+    #[test]
+    fn ordinal_test() {
+        let text_input = "21st 2nd 3RD 11th";
+
+        let output = to_tuple!(EnTokenizer::tokenize(text_input).unwrap());
+        let expected = vec![
+            (Token::Ordinal, "21st"),
+            (Token::Whitespace, " "),
+            (Token::Ordinal, "2nd"),
+            (Token::Whitespace, " "),
+            (Token::Ordinal, "3RD"),
+            (Token::Whitespace, " "),
+            (Token::Ordinal, "11th"),
+        ];
+
+        assert_eq!(output, expected)
+    }
+
     #[test]
     fn decimal_number_basic_test() {
         let text_input = "123.0";
@@ -190,6 +978,434 @@ mod tests {
         assert_eq!(output, expected)
     }
 
+    #[test]
+    fn currency_test() {
+        let text_input = "$5.99 €1.2M £1,000 20€ $";
+
+        let output = to_tuple!(EnTokenizer::tokenize(text_input).unwrap());
+        let expected = vec![
+            (Token::Currency, "$5.99"),
+            (Token::Whitespace, " "),
+            (Token::Currency, "€1.2M"),
+            (Token::Whitespace, " "),
+            (Token::Currency, "£1,000"),
+            (Token::Whitespace, " "),
+            (Token::Currency, "20€"),
+            (Token::Whitespace, " "),
+            (Token::Special, "$"),
+        ];
+
+        assert_eq!(output, expected)
+    }
+
+    #[test]
+    fn currency_boundaries_test() {
+        let text_input = "$5, $5bus 2024, 1,000€";
+
+        let output = to_tuple!(EnTokenizer::tokenize(text_input).unwrap());
+        let expected = vec![
+            (Token::Currency, "$5"),
+            (Token::Special, ","),
+            (Token::Whitespace, " "),
+            (Token::Currency, "$5"),
+            (Token::Word, "bus"),
+            (Token::Whitespace, " "),
+            (Token::DigitSequence, "2024"),
+            (Token::Special, ","),
+            (Token::Whitespace, " "),
+            (Token::Currency, "1,000€"),
+        ];
+
+        assert_eq!(output, expected)
+    }
+
+    #[test]
+    fn numeric_date_test() {
+        let text_input = "01.02.2024 1/2/24 2024-02-01";
+
+        let output = to_tuple!(EnTokenizer::tokenize(text_input).unwrap());
+        let expected = vec![
+            (Token::Date, "01.02.2024"),
+            (Token::Whitespace, " "),
+            (Token::Date, "1/2/24"),
+            (Token::Whitespace, " "),
+            (Token::Date, "2024-02-01"),
+        ];
+
+        assert_eq!(output, expected)
+    }
+
+    #[test]
+    fn invalid_numeric_date_test() {
+        let text_input = "Python 3.10.12 and 13.13.2024 or 45/67/2024";
+
+        let output = to_tuple!(EnTokenizer::tokenize(text_input).unwrap());
+        let expected = vec![
+            (Token::Word, "Python"),
+            (Token::Whitespace, " "),
+            (Token::DecimalNumber, "3.10"),
+            (Token::SentenceDelimiter, "."),
+            (Token::DigitSequence, "12"),
+            (Token::Whitespace, " "),
+            (Token::Word, "and"),
+            (Token::Whitespace, " "),
+            (Token::DecimalNumber, "13.13"),
+            (Token::SentenceDelimiter, "."),
+            (Token::DigitSequence, "2024"),
+            (Token::Whitespace, " "),
+            (Token::Word, "or"),
+            (Token::Whitespace, " "),
+            (Token::Fraction, "45/67"),
+            (Token::Special, "/"),
+            (Token::DigitSequence, "2024"),
+        ];
+
+        assert_eq!(output, expected)
+    }
+
+    #[test]
+    fn written_date_test() {
+        let text_input = "March 3rd, 2024 and 3 March 2024, Dec. 24";
+
+        let output = to_tuple!(EnTokenizer::tokenize(text_input).unwrap());
+        let expected = vec![
+            (Token::Date, "March 3rd, 2024"),
+            (Token::Whitespace, " "),
+            (Token::Word, "and"),
+            (Token::Whitespace, " "),
+            (Token::Date, "3 March 2024"),
+            (Token::Special, ","),
+            (Token::Whitespace, " "),
+            (Token::Date, "Dec. 24"),
+        ];
+
+        assert_eq!(output, expected)
+    }
+
+    #[test]
+    fn month_without_date_test() {
+        let text_input = "March 3rd, hello. May I";
+
+        let output = to_tuple!(EnTokenizer::tokenize(text_input).unwrap());
+        let expected = vec![
+            (Token::Date, "March 3rd"),
+            (Token::Special, ","),
+            (Token::Whitespace, " "),
+            (Token::Word, "hello"),
+            (Token::SentenceDelimiter, "."),
+            (Token::Whitespace, " "),
+            (Token::Word, "May"),
+            (Token::Whitespace, " "),
+            (Token::Word, "I"),
+        ];
+
+        assert_eq!(output, expected)
+    }
+
+    #[test]
+    fn time_test() {
+        let text_input = "10:30, 7:05 pm 3:45:10 7a.m. 5 AM";
+
+        let output = to_tuple!(EnTokenizer::tokenize(text_input).unwrap());
+        let expected = vec![
+            (Token::Time, "10:30"),
+            (Token::Special, ","),
+            (Token::Whitespace, " "),
+            (Token::Time, "7:05 pm"),
+            (Token::Whitespace, " "),
+            (Token::Time, "3:45:10"),
+            (Token::Whitespace, " "),
+            (Token::Time, "7a.m."),
+            (Token::Whitespace, " "),
+            (Token::Time, "5 AM"),
+        ];
+
+        assert_eq!(output, expected)
+    }
+
+    #[test]
+    fn invalid_time_test() {
+        let text_input = "10:75 25:00";
+
+        let output = to_tuple!(EnTokenizer::tokenize(text_input).unwrap());
+        let expected = vec![
+            (Token::DigitSequence, "10"),
+            (Token::Special, ":"),
+            (Token::DigitSequence, "75"),
+            (Token::Whitespace, " "),
+            (Token::DigitSequence, "25"),
+            (Token::Special, ":"),
+            (Token::DigitSequence, "00"),
+        ];
+
+        assert_eq!(output, expected)
+    }
+
+    #[test]
+    fn number_expression_test() {
+        let text_input = "45% 1/3 cup 10-20 2x 0x1F";
+
+        let output = to_tuple!(EnTokenizer::tokenize(text_input).unwrap());
+        let expected = vec![
+            (Token::Percentage, "45%"),
+            (Token::Whitespace, " "),
+            (Token::Fraction, "1/3"),
+            (Token::Whitespace, " "),
+            (Token::Word, "cup"),
+            (Token::Whitespace, " "),
+            (Token::NumberRange, "10-20"),
+            (Token::Whitespace, " "),
+            (Token::Multiplier, "2x"),
+            (Token::Whitespace, " "),
+            (Token::DigitSequence, "0"),
+            (Token::Word, "x"),
+            (Token::DigitSequence, "1"),
+            (Token::Word, "F"),
+        ];
+
+        assert_eq!(output, expected)
+    }
+
+    #[test]
+    fn measurement_test() {
+        let text_input = "5 km, 12kg 30°C 60 km/h 5 kmart";
+
+        let output = to_tuple!(EnTokenizer::tokenize(text_input).unwrap());
+        let expected = vec![
+            (Token::Measurement, "5 km"),
+            (Token::Special, ","),
+            (Token::Whitespace, " "),
+            (Token::Measurement, "12kg"),
+            (Token::Whitespace, " "),
+            (Token::Measurement, "30°C"),
+            (Token::Whitespace, " "),
+            (Token::Measurement, "60 km/h"),
+            (Token::Whitespace, " "),
+            (Token::DigitSequence, "5"),
+            (Token::Whitespace, " "),
+            (Token::Word, "kmart"),
+        ];
+
+        assert_eq!(output, expected)
+    }
+
+    #[test]
+    fn phone_number_test() {
+        let text_input = "(555) 123-4567, +1 800 555 0199 or 1-800-555-0199 and 555-1234";
+        let output = to_tuple!(EnTokenizer::tokenize(text_input).unwrap());
+        let expected = vec![
+            (Token::PhoneNumber, "(555) 123-4567"),
+            (Token::Special, ","),
+            (Token::Whitespace, " "),
+            (Token::PhoneNumber, "+1 800 555 0199"),
+            (Token::Whitespace, " "),
+            (Token::Word, "or"),
+            (Token::Whitespace, " "),
+            (Token::PhoneNumber, "1-800-555-0199"),
+            (Token::Whitespace, " "),
+            (Token::Word, "and"),
+            (Token::Whitespace, " "),
+            (Token::NumberRange, "555-1234"),
+        ];
+
+        assert_eq!(output, expected)
+    }
+
+    #[test]
+    fn phone_number_boundaries_test() {
+        let text_input = "555 1234, 10-20 and +5 or 500-1500";
+        let output = to_tuple!(EnTokenizer::tokenize(text_input).unwrap());
+        let expected = vec![
+            (Token::DigitSequence, "555"),
+            (Token::Whitespace, " "),
+            (Token::DigitSequence, "1234"),
+            (Token::Special, ","),
+            (Token::Whitespace, " "),
+            (Token::NumberRange, "10-20"),
+            (Token::Whitespace, " "),
+            (Token::Word, "and"),
+            (Token::Whitespace, " "),
+            (Token::Special, "+"),
+            (Token::DigitSequence, "5"),
+            (Token::Whitespace, " "),
+            (Token::Word, "or"),
+            (Token::Whitespace, " "),
+            (Token::NumberRange, "500-1500"),
+        ];
+
+        assert_eq!(output, expected)
+    }
+
+    #[test]
+    fn url_test() {
+        let text_input = "See https://docs.rs/phonemoro, www.google.com or docs.rs. Done";
+        let output = to_tuple!(EnTokenizer::tokenize(text_input).unwrap());
+        let expected = vec![
+            (Token::Word, "See"),
+            (Token::Whitespace, " "),
+            (Token::Url, "https://docs.rs/phonemoro"),
+            (Token::Special, ","),
+            (Token::Whitespace, " "),
+            (Token::Url, "www.google.com"),
+            (Token::Whitespace, " "),
+            (Token::Word, "or"),
+            (Token::Whitespace, " "),
+            (Token::Url, "docs.rs"),
+            (Token::SentenceDelimiter, "."),
+            (Token::Whitespace, " "),
+            (Token::Word, "Done"),
+        ];
+
+        assert_eq!(output, expected)
+    }
+
+    #[test]
+    fn email_test() {
+        let text_input = "Mail a.b@example.com or info@my-site.org.";
+        let output = to_tuple!(EnTokenizer::tokenize(text_input).unwrap());
+        let expected = vec![
+            (Token::Word, "Mail"),
+            (Token::Whitespace, " "),
+            (Token::Email, "a.b@example.com"),
+            (Token::Whitespace, " "),
+            (Token::Word, "or"),
+            (Token::Whitespace, " "),
+            (Token::Email, "info@my-site.org"),
+            (Token::SentenceDelimiter, "."),
+        ];
+
+        assert_eq!(output, expected)
+    }
+
+    #[test]
+    fn hyphenated_word_test() {
+        let text_input = "well-known, state-of-the-art and COVID-19 - done";
+        let output = to_tuple!(EnTokenizer::tokenize(text_input).unwrap());
+        let expected = vec![
+            (Token::HyphenatedWord, "well-known"),
+            (Token::Special, ","),
+            (Token::Whitespace, " "),
+            (Token::HyphenatedWord, "state-of-the-art"),
+            (Token::Whitespace, " "),
+            (Token::Word, "and"),
+            (Token::Whitespace, " "),
+            (Token::HyphenatedWord, "COVID-19"),
+            (Token::Whitespace, " "),
+            (Token::Special, "-"),
+            (Token::Whitespace, " "),
+            (Token::Word, "done"),
+        ];
+
+        assert_eq!(output, expected)
+    }
+
+    #[test]
+    fn abbreviation_test() {
+        let text_input = "Dr. Smith, e.g. No. 5. No. word.";
+        let output = to_tuple!(EnTokenizer::tokenize(text_input).unwrap());
+        let expected = vec![
+            (Token::AbbreviatedWord, "Dr."),
+            (Token::Whitespace, " "),
+            (Token::Word, "Smith"),
+            (Token::Special, ","),
+            (Token::Whitespace, " "),
+            (Token::AbbreviatedWord, "e.g."),
+            (Token::Whitespace, " "),
+            (Token::AbbreviatedWord, "No."),
+            (Token::Whitespace, " "),
+            (Token::DigitSequence, "5"),
+            (Token::SentenceDelimiter, "."),
+            (Token::Whitespace, " "),
+            (Token::Word, "No"),
+            (Token::SentenceDelimiter, "."),
+            (Token::Whitespace, " "),
+            (Token::Word, "word"),
+            (Token::SentenceDelimiter, "."),
+        ];
+
+        assert_eq!(output, expected)
+    }
+
+    #[test]
+    fn custom_abbreviation_test() {
+        let text_input = "cf. 5";
+        let is_custom_abbreviation = |abbreviation: &str| abbreviation == "cf";
+        let tokens =
+            EnTokenizer::tokenize_with_abbreviations(text_input, &is_custom_abbreviation).unwrap();
+        let output = to_tuple!(tokens);
+        let expected = vec![
+            (Token::AbbreviatedWord, "cf."),
+            (Token::Whitespace, " "),
+            (Token::DigitSequence, "5"),
+        ];
+
+        assert_eq!(output, expected)
+    }
+
+    #[test]
+    fn split_sentences_test() {
+        let split = |text_input: &'static str| -> Vec<&'static str> {
+            let tokens = EnTokenizer::tokenize(text_input).unwrap();
+            EnTokenizer::split_sentences(&tokens)
+                .into_iter()
+                .map(|range| {
+                    &text_input[tokens[range.start].span.start..tokens[range.end - 1].span.end]
+                })
+                .collect()
+        };
+
+        let expected = vec![
+            "Dr. Smith paid $3.50.",
+            "See docs.rs!",
+            "He said \"Hi.\"",
+            "Why?",
+            "Heading\nText",
+        ];
+        assert_eq!(
+            split("Dr. Smith paid $3.50. See docs.rs! He said \"Hi.\"  Why?\n\nHeading\nText"),
+            expected
+        );
+
+        let expected = vec![
+            "We sell apples, pears, etc.",
+            "It is run by Smith and Sons Ltd.",
+            "Ask Acme Inc.",
+        ];
+        assert_eq!(
+            split("We sell apples, pears, etc. It is run by Smith and Sons Ltd. Ask Acme Inc."),
+            expected
+        );
+
+        // Abbreviations that are often followed by names don't end sentences
+        let expected = vec![
+            "The U.S. Army paid Dr. Smith.",
+            "It was Martin Luther King Jr. Day.",
+        ];
+        assert_eq!(
+            split("The U.S. Army paid Dr. Smith. It was Martin Luther King Jr. Day."),
+            expected
+        );
+        assert_eq!(
+            split("We open at 9 a.m. Monday."),
+            ["We open at 9 a.m. Monday."]
+        );
+
+        // Month abbreviations are only abbreviations before a year, otherwise they can be names
+        let expected = vec!["I met Jan.", "She was nice in Jan. 2024."];
+        assert_eq!(split("I met Jan. She was nice in Jan. 2024."), expected);
+
+        // Delimiters directly followed by a word don't end sentences
+        let expected = vec![
+            "We use Node.js daily.",
+            "Open file.txt now!",
+            "Wait...what?",
+        ];
+        assert_eq!(
+            split("We use Node.js daily. Open file.txt now! Wait...what?"),
+            expected
+        );
+    }
+
     #[test]
     fn sentence_delimiter_basic_test() {
         let text_input = "word.";
@@ -0,0 +1,96 @@
+//! Verbalization of URLs and email addresses, such as "docs.rs/phonemoro" or "a.b@example.com".
+
+use super::constants::{WEB_SYMBOLS, WEB_WORDS};
+use super::numbers;
+
+/// Verbalize a URL or an email address as matched by `Token::Url` or `Token::Email`, e.g. "docs.rs/phonemoro"
+/// becomes "docs dot R S slash phonemoro" and "a.b@example.com" becomes "A dot B at example dot com".
+/// The scheme ("https://") is not read. Short segments are spelled, unless they are in [`WEB_WORDS`].
+/// Spelled letters are uppercase, so that they are looked up as letters and not as words.
+pub fn verbalize(text: &str) -> String {
+    let text = match text.find("://") {
+        Some(i) => &text[i + 3..],
+        None => text,
+    };
+
+    let mut words: Vec<String> = Vec::new();
+    let mut segment_start = 0;
+    for (i, c) in text.char_indices() {
+        if c.is_alphanumeric() {
+            continue;
+        }
+        push_segment(&mut words, &text[segment_start..i]);
+        if let Some(&symbol) = WEB_SYMBOLS.get(&c) {
+            words.push(String::from(symbol));
+        }
+        segment_start = i + c.len_utf8();
+    }
+    push_segment(&mut words, &text[segment_start..]);
+
+    words.join(" ")
+}
+
+/// Add the words of an alphanumeric segment, e.g. "example123" becomes "example one hundred twenty-three".
+fn push_segment(words: &mut Vec<String>, segment: &str) {
+    let mut rest = segment;
+    while let Some(first) = rest.chars().next() {
+        let is_digit = first.is_ascii_digit();
+        let run_end = rest
+            .find(|c: char| c.is_ascii_digit() != is_digit)
+            .unwrap_or(rest.len());
+        let (run, remaining) = rest.split_at(run_end);
+
+        if is_digit {
+            words.push(numbers::cardinal(run));
+        } else if should_spell(run) {
+            words.push(spell(run));
+        } else {
+            words.push(String::from(run));
+        }
+        rest = remaining;
+    }
+}
+
+/// Short segments and segments without vowels are spelled, e.g. "rs" or "www".
+fn should_spell(segment: &str) -> bool {
+    let lowercase = segment.to_lowercase();
+    if WEB_WORDS.contains(lowercase.as_str()) {
+        return false;
+    }
+
+    segment.chars().count() <= 3 || !lowercase.contains(['a', 'e', 'i', 'o', 'u', 'y'])
+}
+
+fn spell(segment: &str) -> String {
+    segment
+        .chars()
+        .map(|c| c.to_uppercase().to_string())
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn url_test() {
+        assert_eq!(
+            verbalize("docs.rs/phonemoro"),
+            "docs dot R S slash phonemoro"
+        );
+        assert_eq!(
+            verbalize("https://www.google.com"),
+            "W W W dot google dot com"
+        );
+        assert_eq!(
+            verbalize("example123.io/a_b"),
+            "example one hundred twenty-three dot I O slash A underscore B"
+        );
+    }
+
+    #[test]
+    fn email_test() {
+        assert_eq!(verbalize("a.b@example.com"), "A dot B at example dot com");
+    }
+}
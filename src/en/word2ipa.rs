@@ -18,6 +18,30 @@ impl TranscriptionLookup for TranscriptionStoreEN {
     }
 }
 
+impl TranscriptionStoreEN {
+    /// Same as `lookup_exact`, but also returns the name of the dict the entry was found in.
+    pub fn lookup_exact_with_dict(
+        &self,
+        graphemes: &str,
+    ) -> Option<(&'static str, &TranscriptionEntry)> {
+        self.dicts.iter().find_map(|dict| {
+            dict.lookup_exact(graphemes)
+                .map(|entry| (dict.dict_name, entry))
+        })
+    }
+
+    /// Same as `lookup_loose`, but also returns the name of the dict the entry was found in.
+    pub fn lookup_loose_with_dict(
+        &self,
+        graphemes: &str,
+    ) -> Option<(&'static str, &TranscriptionEntry)> {
+        self.dicts.iter().find_map(|dict| {
+            dict.lookup_loose(graphemes)
+                .map(|entry| (dict.dict_name, entry))
+        })
+    }
+}
+
 pub static WORD2IPA_EN: TranscriptionStoreEN = TranscriptionStoreEN {
     dicts: [
         TranscriptionDict {